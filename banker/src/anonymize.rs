@@ -0,0 +1,224 @@
+//! Модуль обезличивает операции, чтобы образцы выписок можно было передать
+//! подрядчикам без персональных данных.
+//!
+//! Имена клиентов заменяются псевдонимами из HMAC-SHA256 с секретным ключом:
+//! одно и то же имя с одним ключом всегда даёт один псевдоним, поэтому связи между
+//! операциями сохраняются, а без ключа имя не подобрать. В счетах (`БИК/счёт`, IBAN
+//! и номера без БИК) остаются видны только последние 4 символа. Назначение платежа
+//! удаляется: в нём свободный текст, из которого не вырезать имена надёжно,
+//! а ссылки платежа заменяются токенами по тому же ключу.
+//!
+//! Суммы и даты можно дополнительно сдвинуть на случайную величину в заданных
+//! пределах. Сдвиг тоже выводится из ключа и самой операции, поэтому повторный
+//! запуск с тем же ключом даёт тот же результат. Исходная сумма и дата курса
+//! пересчитанной операции сдвигаются вместе с ней, а источник операции удаляется:
+//! в нём путь к исходному файлу.
+
+use crate::records::{CsvRecord, DATE_FORMAT, parse_date, to_cents};
+
+use chrono::Duration;
+use hmac_sha256::HMAC;
+
+/// Сколько последних символов счёта остаются видны.
+const VISIBLE: usize = 4;
+
+/// Сколько байт HMAC попадает в псевдоним. 8 байт дают совпадение псевдонимов разных
+/// имён с вероятностью порядка 10⁻⁹ даже на миллионе клиентов.
+const TOKEN_BYTES: usize = 8;
+
+/// Наибольший сдвиг даты, 10 лет.
+pub const MAX_DATE_JITTER_DAYS: u64 = 3650;
+
+/// Настройки обезличивания.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AnonymizeOptions {
+    /// Наибольший сдвиг суммы в долях от неё, например `0.05` — до ±5%.
+    pub amount_jitter: f64,
+    /// Наибольший сдвиг даты в днях в обе стороны.
+    pub date_jitter_days: u64,
+}
+
+impl AnonymizeOptions {
+    /// Проверяет, что сдвиг суммы от 0 до 1, а сдвиг даты не больше [MAX_DATE_JITTER_DAYS].
+    pub fn validate(&self) -> Result<(), String> {
+        if !(0.0..1.0).contains(&self.amount_jitter) {
+            return Err("сдвиг суммы задаётся долей от 0 до 1".into());
+        }
+        if self.date_jitter_days > MAX_DATE_JITTER_DAYS {
+            return Err(format!(
+                "сдвиг даты не может быть больше {MAX_DATE_JITTER_DAYS} дней"
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Обезличивает операции секретным ключом.
+#[derive(Clone)]
+pub struct Anonymizer {
+    key: Vec<u8>,
+    options: AnonymizeOptions,
+}
+
+impl Anonymizer {
+    /// Ошибка, если ключ пустой или настройки некорректны, см. [AnonymizeOptions::validate].
+    pub fn new(key: impl Into<Vec<u8>>, options: AnonymizeOptions) -> Result<Self, String> {
+        let key = key.into();
+        if key.is_empty() {
+            return Err("ключ не может быть пустым".into());
+        }
+        options.validate()?;
+        Ok(Self { key, options })
+    }
+
+    /// Псевдоним клиента, например `client_3fa9c2d17be04a61`.
+    pub fn pseudonym(&self, name: &str) -> String {
+        self.token("client", name)
+    }
+
+    fn token(&self, kind: &str, value: &str) -> String {
+        let mac = HMAC::mac(format!("{kind}:{value}"), &self.key);
+        let hex: String = mac[..TOKEN_BYTES]
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+        format!("{kind}_{hex}")
+    }
+
+    /// Обезличивает операции. Ошибка, если для сдвига даты у операции некорректная дата.
+    pub fn apply(&self, records: Vec<CsvRecord>) -> Result<Vec<CsvRecord>, String> {
+        records
+            .into_iter()
+            .map(|record| self.anonymize(record))
+            .collect()
+    }
+
+    fn anonymize(&self, mut record: CsvRecord) -> Result<CsvRecord, String> {
+        // Сдвиги считаются по исходной операции, до замены имён.
+        let seed = HMAC::mac(
+            format!(
+                "jitter:{}:{}:{}:{}:{}",
+                record.transaction,
+                record.from_client,
+                record.to_client,
+                record.amount,
+                record.date
+            ),
+            &self.key,
+        );
+
+        if self.options.amount_jitter > 0.0 {
+            let factor = 1.0 + self.options.amount_jitter * unit(&seed[..8]);
+            record.amount = to_cents(record.amount * factor) as f64 / 100.0;
+            // Иначе исходная сумма выдала бы настоящую.
+            if let Some(conversion) = &mut record.conversion {
+                conversion.original_amount =
+                    to_cents(conversion.original_amount * factor) as f64 / 100.0;
+            }
+        }
+        if self.options.date_jitter_days > 0 {
+            let days = self.options.date_jitter_days;
+            let span = days
+                .checked_mul(2)
+                .and_then(|span| span.checked_add(1))
+                .ok_or("сдвиг даты слишком большой")?;
+            let offset = (u64::from_le_bytes(seed[8..16].try_into().expect("8 байт")) % span)
+                as i64
+                - days as i64;
+            record.date = shift(&record.date, offset, &record.transaction)?;
+            // Дата валютирования сдвигается вместе с датой проводки, разница между ними сохраняется.
+            if let Some(value_date) = &record.value_date {
+                record.value_date = Some(shift(value_date, offset, &record.transaction)?);
+            }
+            if let Some(conversion) = &mut record.conversion {
+                conversion.rate_date = shift(&conversion.rate_date, offset, &record.transaction)?;
+            }
+        }
+
+        record.from_client = self.pseudonym(&record.from_client);
+        record.to_client = self.pseudonym(&record.to_client);
+        record.from_bank = mask_account(&record.from_bank);
+        record.to_bank = mask_account(&record.to_bank);
+        record.description = None;
+        record.source = None;
+        record.end_to_end_id = record.end_to_end_id.map(|id| self.token("ref", &id));
+        record.remittance_reference = record
+            .remittance_reference
+            .map(|reference| self.token("ref", &reference));
+        Ok(record)
+    }
+}
+
+impl std::fmt::Debug for Anonymizer {
+    /// Ключ не выводится.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Anonymizer")
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
+
+fn shift(date: &str, days: i64, transaction: &str) -> Result<String, String> {
+    let date = parse_date(date)
+        .map_err(|err| format!("операция `{transaction}`: некорректная дата: {err}"))?;
+    let shifted = date
+        .checked_add_signed(Duration::days(days))
+        .ok_or_else(|| format!("операция `{transaction}`: дата вышла за допустимые пределы"))?;
+    Ok(shifted.format(DATE_FORMAT).to_string())
+}
+
+/// Скрывает счёт в реквизите банка, кроме последних 4 символов: счёт в паре `БИК/счёт`,
+/// IBAN и номер счёта без БИК. В остальных значениях скрывается каждая группа из
+/// больше чем 4 цифр подряд, буквенные BIC и названия банков не меняются.
+pub fn mask_account(bank: &str) -> String {
+    if let Some((bik, account)) = bank.split_once('/') {
+        return format!("{bik}/{}", mask(account.trim()));
+    }
+
+    let value = bank.trim();
+    let mut chars = value.chars();
+    let is_iban = chars.by_ref().take(2).all(|c| c.is_ascii_alphabetic())
+        && chars.next().is_some_and(|c| c.is_ascii_digit())
+        && value.len() > 8;
+    if is_iban {
+        return mask(value);
+    }
+
+    let mut masked = String::with_capacity(bank.len());
+    let mut digits = String::new();
+    for c in bank.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        masked.push_str(&mask_digits(&digits));
+        digits.clear();
+        masked.push(c);
+    }
+    masked.push_str(&mask_digits(&digits));
+    masked
+}
+
+/// Короткие группы цифр, например номер отделения, остаются как есть.
+fn mask_digits(digits: &str) -> String {
+    if digits.len() > VISIBLE {
+        mask(digits)
+    } else {
+        digits.into()
+    }
+}
+
+fn mask(account: &str) -> String {
+    let count = account.chars().count();
+    account
+        .chars()
+        .enumerate()
+        .map(|(i, c)| if i + VISIBLE < count { '*' } else { c })
+        .collect()
+}
+
+/// Число от -1 до 1 из байтов хеша.
+fn unit(bytes: &[u8]) -> f64 {
+    let value = u64::from_le_bytes(bytes.try_into().expect("8 байт"));
+    (value as f64 / u64::MAX as f64) * 2.0 - 1.0
+}
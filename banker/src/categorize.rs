@@ -0,0 +1,154 @@
+//! Модуль раскладывает операции по категориям для отчётов: зарплата, аренда,
+//! налоги, переводы между своими счетами.
+//!
+//! Правила описываются в `toml` и применяются по порядку, операция получает
+//! категорию первого подходящего правила:
+//!
+//! ```toml
+//! [[category]]
+//! name = "internal"
+//! counterparty = "^ООО Ромашка$"
+//! bank = "bank_a"
+//!
+//! [[category]]
+//! name = "salary"
+//! description = "(?i)заработн|зарплат|salary"
+//! min_amount = 1000
+//!
+//! [[category]]
+//! name = "rent"
+//! counterparty = "(?i)аренд"
+//! description = "(?i)аренд"
+//!
+//! [[category]]
+//! name = "small"
+//! max_amount = 100
+//! ```
+//!
+//! Все условия правила должны выполняться одновременно, правило без условий
+//! подходит любой операции. Условие на назначение платежа не выполняется у операций
+//! без назначения.
+
+use crate::parsers::ParseError;
+use crate::records::CsvRecord;
+
+use regex::Regex;
+use serde::{Deserialize, Deserializer};
+
+/// Упорядоченный набор правил.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Categories {
+    #[serde(rename = "category", default)]
+    pub rules: Vec<CategoryRule>,
+}
+
+/// Правило категории.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CategoryRule {
+    /// Категория, которую получает операция.
+    pub name: String,
+    /// Регулярное выражение для отправителя или получателя.
+    #[serde(default, deserialize_with = "regex")]
+    pub counterparty: Option<Regex>,
+    /// Регулярное выражение для назначения платежа [CsvRecord::description].
+    #[serde(default, deserialize_with = "regex")]
+    pub description: Option<Regex>,
+    /// Банк отправителя или получателя.
+    #[serde(default)]
+    pub bank: Option<String>,
+    /// Наименьшая сумма включительно.
+    #[serde(default)]
+    pub min_amount: Option<f64>,
+    /// Наибольшая сумма включительно.
+    #[serde(default)]
+    pub max_amount: Option<f64>,
+}
+
+impl Categories {
+    /// Читает правила из `toml` и проверяет, что они корректны.
+    pub fn from_toml(rules: &str) -> Result<Self, ParseError> {
+        let categories: Categories =
+            toml::from_str(rules).map_err(|err| ParseError::RulesParseError(err.to_string()))?;
+        categories.validate().map_err(ParseError::RulesParseError)?;
+        Ok(categories)
+    }
+
+    /// Проверяет, что у правил есть названия, а диапазоны сумм не пустые.
+    pub fn validate(&self) -> Result<(), String> {
+        for rule in &self.rules {
+            if rule.name.trim().is_empty() {
+                return Err("у категории должно быть название".into());
+            }
+            if let (Some(min), Some(max)) = (rule.min_amount, rule.max_amount)
+                && min > max
+            {
+                return Err(format!(
+                    "категория `{}`: min_amount {min} больше max_amount {max}",
+                    rule.name
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Категория первого подходящего правила.
+    pub fn category(&self, record: &CsvRecord) -> Option<&str> {
+        self.rules
+            .iter()
+            .find(|rule| rule.matches(record))
+            .map(|rule| rule.name.as_str())
+    }
+
+    /// Записывает категории в [CsvRecord::category]. Операции без подходящего
+    /// правила остаются без категории, см. [uncategorized].
+    pub fn apply(&self, records: Vec<CsvRecord>) -> Vec<CsvRecord> {
+        records
+            .into_iter()
+            .map(|mut record| {
+                record.category = self.category(&record).map(Into::into);
+                record
+            })
+            .collect()
+    }
+}
+
+impl CategoryRule {
+    /// Операция подходит под все условия правила.
+    pub fn matches(&self, record: &CsvRecord) -> bool {
+        let counterparty = self.counterparty.as_ref().is_none_or(|regex| {
+            regex.is_match(&record.from_client) || regex.is_match(&record.to_client)
+        });
+        let description = self.description.as_ref().is_none_or(|regex| {
+            record
+                .description
+                .as_deref()
+                .is_some_and(|description| regex.is_match(description))
+        });
+        let bank = self
+            .bank
+            .as_ref()
+            .is_none_or(|bank| *bank == record.from_bank || *bank == record.to_bank);
+        let min = self.min_amount.is_none_or(|min| record.amount >= min);
+        let max = self.max_amount.is_none_or(|max| record.amount <= max);
+        counterparty && description && bank && min && max
+    }
+}
+
+/// Номера операций без категории, начиная с 0.
+pub fn uncategorized(records: &[CsvRecord]) -> Vec<usize> {
+    records
+        .iter()
+        .enumerate()
+        .filter(|(_, record)| record.category.is_none())
+        .map(|(index, _)| index)
+        .collect()
+}
+
+fn regex<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Regex>, D::Error> {
+    let pattern = String::deserialize(deserializer)?;
+    Regex::new(&pattern)
+        .map(Some)
+        .map_err(|err| serde::de::Error::custom(format!("некорректное выражение: {err}")))
+}
@@ -0,0 +1,218 @@
+//! Модуль описывает общую для `parquet` и `arrow` колоночную схему банковских операций.
+
+use crate::CsvRecord;
+use crate::records::{DATE_FORMAT, parse_date};
+
+use arrow::array::{
+    Array, ArrayRef, Date32Array, Decimal128Array, DictionaryArray, RecordBatch, StringArray,
+};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Date32Type, Field, Int32Type, Schema, SchemaRef};
+use arrow::error::ArrowError;
+
+use std::sync::Arc;
+
+/// Точность колонки `amount`.
+pub const AMOUNT_PRECISION: u8 = 18;
+/// Количество знаков после запятой в колонке `amount`.
+pub const AMOUNT_SCALE: i8 = 2;
+
+/// Алгоритм сжатия колоночных файлов.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Compression {
+    /// Без сжатия.
+    None,
+    /// Snappy, только для `parquet`.
+    Snappy,
+    /// Gzip, только для `parquet`.
+    Gzip,
+    /// Lz4.
+    Lz4,
+    /// Zstd.
+    #[default]
+    Zstd,
+}
+
+/// Возвращает схему колонок: суммы хранятся как `Decimal128(18, 2)`, даты как `Date32`,
+/// названия банков как словарные строки. Колонки необязательных полей допускают пустые значения.
+pub fn schema() -> SchemaRef {
+    let bank = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+
+    Arc::new(Schema::new(vec![
+        Field::new("from_client", DataType::Utf8, false),
+        Field::new("from_bank", bank.clone(), false),
+        Field::new("to_client", DataType::Utf8, false),
+        Field::new("to_bank", bank, false),
+        Field::new("transaction", DataType::Utf8, false),
+        Field::new(
+            "amount",
+            DataType::Decimal128(AMOUNT_PRECISION, AMOUNT_SCALE),
+            false,
+        ),
+        Field::new("date", DataType::Date32, false),
+        Field::new("description", DataType::Utf8, true),
+        Field::new("end_to_end_id", DataType::Utf8, true),
+        Field::new("remittance_reference", DataType::Utf8, true),
+        Field::new("value_date", DataType::Date32, true),
+    ]))
+}
+
+/// Собирает [RecordBatch] из списка операций.
+pub fn to_batch(records: &[CsvRecord]) -> Result<RecordBatch, ArrowError> {
+    let strings = |f: fn(&CsvRecord) -> &str| -> ArrayRef {
+        Arc::new(records.iter().map(f).map(Some).collect::<StringArray>())
+    };
+    let details = |f: fn(&CsvRecord) -> Option<&str>| -> ArrayRef {
+        Arc::new(records.iter().map(f).collect::<StringArray>())
+    };
+    let banks = |f: fn(&CsvRecord) -> &str| -> ArrayRef {
+        Arc::new(
+            records
+                .iter()
+                .map(f)
+                .collect::<DictionaryArray<Int32Type>>(),
+        )
+    };
+
+    let amounts = records
+        .iter()
+        .map(|r| Some(to_decimal(r.amount)))
+        .collect::<Decimal128Array>()
+        .with_precision_and_scale(AMOUNT_PRECISION, AMOUNT_SCALE)?;
+
+    let dates = records
+        .iter()
+        .map(|r| to_days(&r.date).map(Some))
+        .collect::<Result<Date32Array, _>>()?;
+
+    let value_dates = records
+        .iter()
+        .map(|r| r.value_date.as_deref().map(to_days).transpose())
+        .collect::<Result<Date32Array, _>>()?;
+
+    RecordBatch::try_new(
+        schema(),
+        vec![
+            strings(|r| &r.from_client),
+            banks(|r| &r.from_bank),
+            strings(|r| &r.to_client),
+            banks(|r| &r.to_bank),
+            strings(|r| &r.transaction),
+            Arc::new(amounts),
+            Arc::new(dates),
+            details(|r| r.description.as_deref()),
+            details(|r| r.end_to_end_id.as_deref()),
+            details(|r| r.remittance_reference.as_deref()),
+            Arc::new(value_dates),
+        ],
+    )
+}
+
+/// Разбирает [RecordBatch] в список операций. Колонки ищутся по имени и приводятся
+/// к типам из [schema], так что читаются и файлы, записанные другими инструментами.
+/// Колонки необязательных полей могут отсутствовать.
+pub fn from_batch(batch: &RecordBatch) -> Result<Vec<CsvRecord>, ArrowError> {
+    let optional = |name: &str, data_type: &DataType| -> Result<Option<ArrayRef>, ArrowError> {
+        batch
+            .column_by_name(name)
+            .map(|column| cast(column, data_type))
+            .transpose()
+    };
+    let column = |name: &str, data_type: &DataType| -> Result<ArrayRef, ArrowError> {
+        let column = optional(name, data_type)?
+            .ok_or_else(|| ArrowError::SchemaError(format!("отсутствует колонка `{name}`")))?;
+        if column.null_count() > 0 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "колонка `{name}` содержит пустые значения"
+            )));
+        }
+        Ok(column)
+    };
+    let strings = |name: &str| -> Result<StringArray, ArrowError> {
+        let column = column(name, &DataType::Utf8)?;
+        Ok(column
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap()
+            .clone())
+    };
+
+    let from_client = strings("from_client")?;
+    let from_bank = strings("from_bank")?;
+    let to_client = strings("to_client")?;
+    let to_bank = strings("to_bank")?;
+    let transaction = strings("transaction")?;
+
+    let amount = column(
+        "amount",
+        &DataType::Decimal128(AMOUNT_PRECISION, AMOUNT_SCALE),
+    )?;
+    let amount = amount.as_any().downcast_ref::<Decimal128Array>().unwrap();
+
+    let date = column("date", &DataType::Date32)?;
+    let date = date.as_any().downcast_ref::<Date32Array>().unwrap();
+
+    let details = |name: &str| -> Result<Option<StringArray>, ArrowError> {
+        Ok(optional(name, &DataType::Utf8)?.map(|column| {
+            column
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap()
+                .clone()
+        }))
+    };
+    let description = details("description")?;
+    let end_to_end_id = details("end_to_end_id")?;
+    let remittance_reference = details("remittance_reference")?;
+    let value_date = optional("value_date", &DataType::Date32)?;
+    let value_date = value_date
+        .as_ref()
+        .map(|column| column.as_any().downcast_ref::<Date32Array>().unwrap());
+
+    let text = |column: &Option<StringArray>, i: usize| {
+        column
+            .as_ref()
+            .filter(|column| column.is_valid(i))
+            .map(|column| column.value(i).to_string())
+    };
+
+    Ok((0..batch.num_rows())
+        .map(|i| CsvRecord {
+            description: text(&description, i),
+            end_to_end_id: text(&end_to_end_id, i),
+            remittance_reference: text(&remittance_reference, i),
+            value_date: value_date
+                .filter(|column| column.is_valid(i))
+                .map(|column| from_days(column.value(i))),
+            ..CsvRecord::new(
+                from_client.value(i),
+                from_bank.value(i),
+                to_client.value(i),
+                to_bank.value(i),
+                transaction.value(i),
+                from_decimal(amount.value(i)),
+                from_days(date.value(i)),
+            )
+        })
+        .collect())
+}
+
+fn to_decimal(amount: f64) -> i128 {
+    (amount * 10f64.powi(AMOUNT_SCALE.into())).round() as i128
+}
+
+fn from_decimal(amount: i128) -> f64 {
+    amount as f64 / 10f64.powi(AMOUNT_SCALE.into())
+}
+
+fn to_days(date: &str) -> Result<i32, ArrowError> {
+    let date = parse_date(date)
+        .map_err(|err| ArrowError::ParseError(format!("некорректная дата `{date}`: {err}")))?;
+    Ok(Date32Type::from_naive_date(date))
+}
+
+fn from_days(days: i32) -> String {
+    Date32Type::to_naive_date(days)
+        .format(DATE_FORMAT)
+        .to_string()
+}
@@ -0,0 +1,158 @@
+//! Модуль ищет и удаляет повторяющиеся операции, например когда банк
+//! повторно прислал выписку.
+//!
+//! Операции считаются дубликатами, если у них совпадают значения ключевых полей
+//! [DedupeOptions::keys]. С окном [DedupeOptions::window_days] дата в ключ не входит:
+//! операции с одинаковыми ключами попадают в одну группу, если они не позже
+//! заданного количества дней после первой по дате операции группы. Так цепочка
+//! операций с шагом в окно не сливается в одну группу.
+
+use crate::records::{CsvRecord, FIELDS, parse_date};
+
+use chrono::NaiveDate;
+
+use std::collections::HashMap;
+
+/// Настройки поиска дубликатов.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DedupeOptions {
+    /// Поля из [FIELDS], по которым операции считаются одинаковыми.
+    pub keys: Vec<String>,
+    /// Допустимая разница дат в днях между дубликатами.
+    pub window_days: Option<u64>,
+}
+
+impl Default for DedupeOptions {
+    fn default() -> Self {
+        Self {
+            keys: vec!["transaction".into()],
+            window_days: None,
+        }
+    }
+}
+
+/// Какую операцию из группы дубликатов оставить.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Keep {
+    #[default]
+    First,
+    Last,
+}
+
+impl DedupeOptions {
+    /// Проверяет, что ключи известны и не противоречат окну дат.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.keys.is_empty() {
+            return Err("нужно хотя бы одно ключевое поле".into());
+        }
+        if let Some(key) = self.keys.iter().find(|k| !FIELDS.contains(&k.as_str())) {
+            return Err(format!("неизвестное поле `{key}`"));
+        }
+        if self.window_days.is_some() && self.keys.iter().any(|k| k == "date") {
+            return Err("с окном дат поле `date` не может быть ключом".into());
+        }
+        Ok(())
+    }
+}
+
+/// Находит группы дубликатов. Каждая группа содержит номера операций
+/// (начиная с 0) по возрастанию, в группе не меньше двух операций.
+/// Группы упорядочены по первой операции.
+pub fn duplicates(
+    records: &[CsvRecord],
+    options: &DedupeOptions,
+) -> Result<Vec<Vec<usize>>, String> {
+    options.validate()?;
+
+    let mut by_key: HashMap<Vec<String>, Vec<usize>> = HashMap::new();
+    let mut order = Vec::new();
+    for (index, record) in records.iter().enumerate() {
+        let key: Vec<String> = options
+            .keys
+            .iter()
+            .map(|k| record.field(k).unwrap_or_default())
+            .collect();
+        let group = by_key.entry(key).or_insert_with_key(|key| {
+            order.push(key.clone());
+            Vec::new()
+        });
+        group.push(index);
+    }
+
+    let mut groups = Vec::new();
+    for key in order {
+        let group = by_key.remove(&key).unwrap_or_default();
+        match options.window_days {
+            Some(days) => groups.extend(split_by_window(records, group, days)?),
+            None => groups.push(group),
+        }
+    }
+
+    groups.retain(|group| group.len() > 1);
+    groups.sort_by_key(|group| group[0]);
+    Ok(groups)
+}
+
+/// Удаляет дубликаты, оставляя из каждой группы первую или последнюю операцию.
+/// Порядок оставшихся операций не меняется.
+pub fn dedupe(
+    records: Vec<CsvRecord>,
+    options: &DedupeOptions,
+    keep: Keep,
+) -> Result<Vec<CsvRecord>, String> {
+    let mut removed = vec![false; records.len()];
+    for group in duplicates(&records, options)? {
+        let kept = match keep {
+            Keep::First => group[0],
+            Keep::Last => group[group.len() - 1],
+        };
+        for index in group.into_iter().filter(|&i| i != kept) {
+            removed[index] = true;
+        }
+    }
+
+    Ok(records
+        .into_iter()
+        .zip(removed)
+        .filter(|(_, removed)| !removed)
+        .map(|(record, _)| record)
+        .collect())
+}
+
+/// Делит операции с одинаковыми ключами на группы: даты операций группы отличаются
+/// от первой даты группы не больше чем на `days` дней.
+fn split_by_window(
+    records: &[CsvRecord],
+    group: Vec<usize>,
+    days: u64,
+) -> Result<Vec<Vec<usize>>, String> {
+    let mut dated: Vec<(NaiveDate, usize)> = group
+        .into_iter()
+        .map(|index| {
+            let date = &records[index].date;
+            parse_date(date)
+                .map(|parsed| (parsed, index))
+                .map_err(|err| format!("некорректная дата `{date}`: {err}"))
+        })
+        .collect::<Result<_, _>>()?;
+    dated.sort();
+
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut start: Option<NaiveDate> = None;
+    for (date, index) in dated {
+        match (start, groups.last_mut()) {
+            (Some(first), Some(last)) if (date - first).num_days().unsigned_abs() <= days => {
+                last.push(index);
+            }
+            _ => {
+                groups.push(vec![index]);
+                start = Some(date);
+            }
+        }
+    }
+
+    for group in &mut groups {
+        group.sort();
+    }
+    Ok(groups)
+}
@@ -0,0 +1,149 @@
+//! Модуль сравнивает две выписки, например исходную и перевыпущенную банком,
+//! и находит добавленные, удалённые и изменённые операции.
+//!
+//! Операции сопоставляются по ключевым полям (по умолчанию номер транзакции).
+//! Если ключ повторяется, первая такая операция старой выписки сравнивается
+//! с первой операцией новой, вторая со второй и так далее.
+
+use crate::printers::diff_printer;
+use crate::records::{CsvRecord, DETAILS, FIELDS, to_cents};
+
+use serde::Serialize;
+
+use std::collections::{HashMap, VecDeque};
+use std::io::{self, Write};
+
+/// Изменившееся поле операции.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Change {
+    pub field: String,
+    pub old: String,
+    pub new: String,
+}
+
+/// Операция, которая есть в обеих выписках, но отличается полями.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Modified {
+    /// Значения ключевых полей в порядке [Diff::key].
+    pub key: Vec<String>,
+    pub changes: Vec<Change>,
+}
+
+/// Различия между выписками.
+#[derive(Debug, Serialize)]
+pub struct Diff {
+    /// Поля из [FIELDS], по которым сопоставлялись операции.
+    pub key: Vec<String>,
+    /// Операции, которых нет в старой выписке, в порядке новой.
+    pub added: Vec<CsvRecord>,
+    /// Операции, которых нет в новой выписке, в порядке старой.
+    pub removed: Vec<CsvRecord>,
+    /// Изменённые операции в порядке старой выписки.
+    pub modified: Vec<Modified>,
+    /// Сколько операций совпало полностью.
+    pub unchanged: usize,
+}
+
+/// Сравнивает выписки по ключевым полям.
+/// Ошибка, если ключевых полей нет или среди них есть неизвестное.
+pub fn diff(old: Vec<CsvRecord>, new: Vec<CsvRecord>, key: &[String]) -> Result<Diff, String> {
+    if key.is_empty() {
+        return Err("нужно хотя бы одно ключевое поле".into());
+    }
+    if let Some(field) = key.iter().find(|f| !FIELDS.contains(&f.as_str())) {
+        return Err(format!("неизвестное поле `{field}`"));
+    }
+
+    let key_of = |record: &CsvRecord| -> Vec<String> {
+        key.iter()
+            .map(|field| record.field(field).unwrap_or_default())
+            .collect()
+    };
+
+    let mut by_key: HashMap<Vec<String>, VecDeque<CsvRecord>> = HashMap::new();
+    let mut order = Vec::new();
+    for record in new {
+        let record_key = key_of(&record);
+        order.push(record_key.clone());
+        by_key.entry(record_key).or_default().push_back(record);
+    }
+
+    let mut result = Diff {
+        key: key.to_vec(),
+        added: Vec::new(),
+        removed: Vec::new(),
+        modified: Vec::new(),
+        unchanged: 0,
+    };
+
+    for record in old {
+        let record_key = key_of(&record);
+        match by_key.get_mut(&record_key).and_then(VecDeque::pop_front) {
+            Some(other) => {
+                let changes = changes(&record, &other);
+                if changes.is_empty() {
+                    result.unchanged += 1;
+                } else {
+                    result.modified.push(Modified {
+                        key: record_key,
+                        changes,
+                    });
+                }
+            }
+            None => result.removed.push(record),
+        }
+    }
+
+    // Оставшиеся операции новой выписки добавлены, порядок восстанавливается по `order`.
+    for record_key in order {
+        if let Some(record) = by_key.get_mut(&record_key).and_then(VecDeque::pop_front) {
+            result.added.push(record);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Поля, которыми отличаются операции, включая необязательные из [DETAILS].
+/// Суммы сравниваются с точностью до копейки, незаполненное поле записывается пустым.
+fn changes(old: &CsvRecord, new: &CsvRecord) -> Vec<Change> {
+    let fields = FIELDS
+        .iter()
+        .filter(|&&field| match field {
+            "amount" => to_cents(old.amount) != to_cents(new.amount),
+            field => old.field(field) != new.field(field),
+        })
+        .map(|&field| Change {
+            field: field.into(),
+            old: old.field(field).unwrap_or_default(),
+            new: new.field(field).unwrap_or_default(),
+        });
+    let details = DETAILS
+        .iter()
+        .filter(|&&detail| old.detail(detail) != new.detail(detail))
+        .map(|&detail| Change {
+            field: detail.into(),
+            old: old.detail(detail).unwrap_or_default().into(),
+            new: new.detail(detail).unwrap_or_default().into(),
+        });
+    fields.chain(details).collect()
+}
+
+impl Diff {
+    /// Выписки совпадают.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+
+    /// Записывает различия текстом: `+` добавленные, `-` удалённые, `~` изменённые операции
+    /// и итоговая строка.
+    pub fn print_text(&self, writer: impl Write) -> Result<(), io::Error> {
+        diff_printer::print_text(writer, self)
+    }
+
+    /// Записывает различия объектом `json` с ключами `key`, `added`, `removed`, `modified`
+    /// и `unchanged`, как в [Diff].
+    pub fn print_json(&self, writer: impl Write) -> Result<(), io::Error> {
+        diff_printer::print_json(writer, self)
+    }
+}
@@ -0,0 +1,107 @@
+//! Модуль определяет направление операций относительно «нашей» стороны:
+//! списание [Direction::Debit] или поступление [Direction::Credit].
+//!
+//! В модели операции сумма всегда положительная, а направление задаётся
+//! отправителем и получателем. Для выписок по одному счёту удобнее суммы со знаком,
+//! их даёт [Party::sign_amounts].
+
+use crate::records::CsvRecord;
+
+use std::error::Error;
+use std::fmt::Display;
+
+/// Направление операции относительно нашей стороны.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Списание: мы отправитель.
+    Debit,
+    /// Поступление: мы получатель.
+    Credit,
+}
+
+/// Наша сторона операций: клиент и, если нужно различать счета клиента, банк.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Party {
+    pub client: String,
+    /// Банк клиента. Если не задан, подходит любой банк.
+    pub bank: Option<String>,
+}
+
+/// Операция, в которой наша сторона не участвует или участвует с обеих сторон,
+/// поэтому направление не определить.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownDirection {
+    /// Номер операции.
+    pub transaction: String,
+}
+
+impl Party {
+    pub fn new(client: impl Into<String>) -> Self {
+        Self {
+            client: client.into(),
+            bank: None,
+        }
+    }
+
+    /// Ограничивает нашу сторону счетами в одном банке.
+    pub fn with_bank(mut self, bank: impl Into<String>) -> Self {
+        self.bank = Some(bank.into());
+        self
+    }
+
+    /// Определяет направление операции. Возвращает `None`, если мы не участвуем
+    /// в операции или переводим сами себе.
+    pub fn direction(&self, record: &CsvRecord) -> Option<Direction> {
+        let sender = self.is(&record.from_client, &record.from_bank);
+        let receiver = self.is(&record.to_client, &record.to_bank);
+        match (sender, receiver) {
+            (true, false) => Some(Direction::Debit),
+            (false, true) => Some(Direction::Credit),
+            _ => None,
+        }
+    }
+
+    /// Сумма операции со знаком: поступления положительные, списания отрицательные.
+    pub fn signed_amount(&self, record: &CsvRecord) -> Option<f64> {
+        self.direction(record).map(|direction| match direction {
+            Direction::Debit => -record.amount,
+            Direction::Credit => record.amount,
+        })
+    }
+
+    /// Заменяет суммы операций на суммы со знаком, например для записей [crate::statement::Statement].
+    /// Ошибка, если направление хотя бы одной операции не определить.
+    pub fn sign_amounts(
+        &self,
+        records: Vec<CsvRecord>,
+    ) -> Result<Vec<CsvRecord>, UnknownDirection> {
+        records
+            .into_iter()
+            .map(|mut record| {
+                record.amount = self
+                    .signed_amount(&record)
+                    .ok_or_else(|| UnknownDirection {
+                        transaction: record.transaction.clone(),
+                    })?;
+                Ok(record)
+            })
+            .collect()
+    }
+
+    fn is(&self, client: &str, bank: &str) -> bool {
+        client == self.client && self.bank.as_ref().is_none_or(|b| b == bank)
+    }
+}
+
+impl Error for UnknownDirection {}
+
+impl Display for UnknownDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "не получилось определить направление операции `{}`: \
+            наша сторона не отправитель и не получатель",
+            self.transaction
+        )
+    }
+}
@@ -0,0 +1,443 @@
+//! Модуль содержит язык фильтров для отбора операций, например:
+//!
+//! ```text
+//! amount > 10000 && from_bank == "bank_a" && date >= 2025-01-01
+//! ```
+//!
+//! Условие сравнивает поле из [crate::records::FIELDS] со значением: числом,
+//! строкой в кавычках или датой `YYYY-MM-DD`. Условия объединяются через `&&` и `||`,
+//! отрицаются через `!` и группируются скобками. Операторы сравнения:
+//! `==`, `!=`, `>`, `>=`, `<`, `<=`.
+//!
+//! Поля и типы значений проверяются при разборе фильтра, поэтому опечатка
+//! в названии поля или сравнение суммы со строкой дают ошибку сразу.
+
+use crate::records::{CsvRecord, FIELDS, parse_date};
+use crate::value::{Kind, Value, kind, record_value};
+
+use chrono::NaiveDate;
+
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Наибольшая вложенность скобок и отрицаний, глубже разбор не спускается,
+/// чтобы длинное `((((…` не переполнило стек.
+const MAX_DEPTH: usize = 64;
+
+/// Разобранный фильтр операций.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter {
+    expr: Expr,
+}
+
+/// Ошибка разбора или применения фильтра.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterError {
+    /// Позиция символа в выражении, начиная с 0, если ошибка относится к выражению.
+    pub position: Option<usize>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Compare {
+        field: String,
+        op: CompareOp,
+        value: Value,
+    },
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Literal(Value),
+    Compare(CompareOp),
+    And,
+    Or,
+    Not,
+    LParen,
+    RParen,
+}
+
+impl Filter {
+    /// Разбирает выражение фильтра.
+    pub fn parse(expr: &str) -> Result<Self, FilterError> {
+        let tokens = tokenize(expr)?;
+        let mut parser = Parser {
+            tokens,
+            position: 0,
+            end: expr.chars().count(),
+            depth: 0,
+        };
+        let expr = parser.or()?;
+        if let Some((position, token)) = parser.peek() {
+            return Err(FilterError::at(
+                *position,
+                format!("лишний {} после конца выражения", token.describe()),
+            ));
+        }
+        Ok(Self { expr })
+    }
+
+    /// Проверяет, подходит ли операция под фильтр.
+    /// Ошибка, если у операции некорректная дата, а фильтр её сравнивает.
+    pub fn matches(&self, record: &CsvRecord) -> Result<bool, FilterError> {
+        self.expr.eval(record)
+    }
+
+    /// Оставляет только операции, подходящие под фильтр.
+    pub fn apply(&self, records: Vec<CsvRecord>) -> Result<Vec<CsvRecord>, FilterError> {
+        let mut filtered = Vec::new();
+        for record in records {
+            if self.matches(&record)? {
+                filtered.push(record);
+            }
+        }
+        Ok(filtered)
+    }
+}
+
+impl FromStr for Filter {
+    type Err = FilterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Filter::parse(s)
+    }
+}
+
+impl Expr {
+    fn eval(&self, record: &CsvRecord) -> Result<bool, FilterError> {
+        match self {
+            Expr::Compare { field, op, value } => {
+                let actual = record_value(record, field).map_err(|message| FilterError {
+                    position: None,
+                    message: format!("операция `{}`: {message}", record.transaction),
+                })?;
+                let ordering = actual.partial_cmp(value);
+                Ok(match op {
+                    CompareOp::Eq => ordering == Some(Ordering::Equal),
+                    CompareOp::Ne => ordering != Some(Ordering::Equal),
+                    CompareOp::Gt => ordering == Some(Ordering::Greater),
+                    CompareOp::Ge => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+                    CompareOp::Lt => ordering == Some(Ordering::Less),
+                    CompareOp::Le => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+                })
+            }
+            Expr::Not(expr) => Ok(!expr.eval(record)?),
+            Expr::And(left, right) => Ok(left.eval(record)? && right.eval(record)?),
+            Expr::Or(left, right) => Ok(left.eval(record)? || right.eval(record)?),
+        }
+    }
+}
+
+/// Разбор по грамматике:
+///
+/// ```text
+/// or      = and ("||" and)*
+/// and     = unary ("&&" unary)*
+/// unary   = "!" unary | "(" or ")" | field compare literal
+/// ```
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    position: usize,
+    end: usize,
+    /// Текущая вложенность скобок и отрицаний.
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&(usize, Token)> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self, expected: &str) -> Result<(usize, Token), FilterError> {
+        let token = self.tokens.get(self.position).cloned().ok_or_else(|| {
+            FilterError::at(
+                self.end,
+                format!("выражение оборвалось, ожидалось {expected}"),
+            )
+        })?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn or(&mut self) -> Result<Expr, FilterError> {
+        let mut expr = self.and()?;
+        while let Some((_, Token::Or)) = self.peek() {
+            self.position += 1;
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr, FilterError> {
+        let mut expr = self.unary()?;
+        while let Some((_, Token::And)) = self.peek() {
+            self.position += 1;
+            expr = Expr::And(Box::new(expr), Box::new(self.unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<Expr, FilterError> {
+        match self.next("условие")? {
+            (position, Token::Not) => {
+                self.enter(position)?;
+                let expr = self.unary()?;
+                self.depth -= 1;
+                Ok(Expr::Not(Box::new(expr)))
+            }
+            (position, Token::LParen) => {
+                self.enter(position)?;
+                let expr = self.or()?;
+                self.depth -= 1;
+                match self.next("`)`")? {
+                    (_, Token::RParen) => Ok(expr),
+                    (position, token) => Err(FilterError::at(
+                        position,
+                        format!("ожидалась `)`, получен {}", token.describe()),
+                    )),
+                }
+            }
+            (position, Token::Ident(field)) => self.compare(position, field),
+            (position, token) => Err(FilterError::at(
+                position,
+                format!("ожидалось название поля, получен {}", token.describe()),
+            )),
+        }
+    }
+
+    fn enter(&mut self, position: usize) -> Result<(), FilterError> {
+        if self.depth == MAX_DEPTH {
+            return Err(FilterError::at(
+                position,
+                format!("скобки и отрицания вложены глубже {MAX_DEPTH} уровней"),
+            ));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn compare(&mut self, position: usize, field: String) -> Result<Expr, FilterError> {
+        if !FIELDS.contains(&field.as_str()) {
+            return Err(FilterError::at(
+                position,
+                format!(
+                    "неизвестное поле `{field}`, доступны: {}",
+                    FIELDS.join(", ")
+                ),
+            ));
+        }
+
+        let op = match self.next("оператор сравнения")? {
+            (_, Token::Compare(op)) => op,
+            (position, token) => {
+                return Err(FilterError::at(
+                    position,
+                    format!(
+                        "после поля `{field}` ожидался оператор сравнения, получен {}",
+                        token.describe()
+                    ),
+                ));
+            }
+        };
+
+        let (value_position, value) = match self.next("значение")? {
+            (position, Token::Literal(value)) => (position, value),
+            (position, token) => {
+                return Err(FilterError::at(
+                    position,
+                    format!("ожидалось значение, получен {}", token.describe()),
+                ));
+            }
+        };
+
+        let value =
+            coerce(&field, value).map_err(|message| FilterError::at(value_position, message))?;
+        Ok(Expr::Compare { field, op, value })
+    }
+}
+
+/// Приводит значение к типу поля: строка с датой подходит для `date`.
+fn coerce(field: &str, value: Value) -> Result<Value, String> {
+    let expected = kind(field);
+    match (expected, value) {
+        (Kind::Date, Value::Text(text)) => parse_date(&text)
+            .or_else(|_| NaiveDate::parse_from_str(&text, "%Y-%m-%d"))
+            .map(Value::Date)
+            .map_err(|_| format!("поле `{field}` дата, а `\"{text}\"` не похоже на дату")),
+        (expected, value) if value.kind() == expected => Ok(value),
+        (expected, value) => Err(format!(
+            "поле `{field}` {expected}, а `{value}` {}, их нельзя сравнить",
+            value.kind()
+        )),
+    }
+}
+
+fn tokenize(expr: &str) -> Result<Vec<(usize, Token)>, FilterError> {
+    let chars: Vec<char> = expr.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        let two = |second: char| chars.get(i + 1) == Some(&second);
+
+        let token = match c {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '&' if two('&') => Token::And,
+            '|' if two('|') => Token::Or,
+            '=' if two('=') => Token::Compare(CompareOp::Eq),
+            '!' if two('=') => Token::Compare(CompareOp::Ne),
+            '>' if two('=') => Token::Compare(CompareOp::Ge),
+            '<' if two('=') => Token::Compare(CompareOp::Le),
+            '!' => Token::Not,
+            '>' => Token::Compare(CompareOp::Gt),
+            '<' => Token::Compare(CompareOp::Lt),
+            '"' => {
+                let mut text = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => {
+                            return Err(FilterError::at(start, "незакрытая строка".into()));
+                        }
+                        Some('"') => break,
+                        Some('\\') if chars.get(i + 1).is_some() => {
+                            text.push(chars[i + 1]);
+                            i += 2;
+                        }
+                        Some(&c) => {
+                            text.push(c);
+                            i += 1;
+                        }
+                    }
+                }
+                Token::Literal(Value::Text(text))
+            }
+            c if c.is_ascii_digit() || c == '-' || c == '.' => {
+                let mut end = i + 1;
+                while end < chars.len()
+                    && (chars[end].is_ascii_digit() || matches!(chars[end], '-' | '.'))
+                {
+                    end += 1;
+                }
+                let literal: String = chars[i..end].iter().collect();
+                i = end;
+                tokens.push((start, literal_token(&literal, start)?));
+                continue;
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut end = i + 1;
+                while end < chars.len() && (chars[end].is_alphanumeric() || chars[end] == '_') {
+                    end += 1;
+                }
+                let ident: String = chars[i..end].iter().collect();
+                i = end;
+                tokens.push((start, Token::Ident(ident)));
+                continue;
+            }
+            c => {
+                return Err(FilterError::at(start, format!("неожиданный символ `{c}`")));
+            }
+        };
+
+        i += match token {
+            Token::And | Token::Or => 2,
+            Token::Compare(CompareOp::Eq | CompareOp::Ne | CompareOp::Ge | CompareOp::Le) => 2,
+            _ => 1,
+        };
+        tokens.push((start, token));
+    }
+
+    Ok(tokens)
+}
+
+/// Число или дата без кавычек: `10000`, `-5.5`, `2025-01-01`, `24-01-2025`.
+fn literal_token(literal: &str, position: usize) -> Result<Token, FilterError> {
+    if let Ok(date) = NaiveDate::parse_from_str(literal, "%Y-%m-%d") {
+        return Ok(Token::Literal(Value::Date(date)));
+    }
+    if let Ok(date) = parse_date(literal) {
+        return Ok(Token::Literal(Value::Date(date)));
+    }
+    literal
+        .parse()
+        .map(|number| Token::Literal(Value::Number(number)))
+        .map_err(|_| FilterError::at(position, format!("`{literal}` не число и не дата")))
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Token::Ident(ident) => format!("идентификатор `{ident}`"),
+            Token::Literal(value) => format!("литерал `{value}`"),
+            Token::Compare(op) => format!("оператор `{op}`"),
+            Token::And => "оператор `&&`".into(),
+            Token::Or => "оператор `||`".into(),
+            Token::Not => "оператор `!`".into(),
+            Token::LParen => "символ `(`".into(),
+            Token::RParen => "символ `)`".into(),
+        }
+    }
+}
+
+impl FilterError {
+    fn at(position: usize, message: String) -> Self {
+        Self {
+            position: Some(position),
+            message,
+        }
+    }
+}
+
+impl Error for FilterError {}
+
+impl Display for CompareOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use CompareOp::*;
+        match self {
+            Eq => write!(f, "=="),
+            Ne => write!(f, "!="),
+            Gt => write!(f, ">"),
+            Ge => write!(f, ">="),
+            Lt => write!(f, "<"),
+            Le => write!(f, "<="),
+        }
+    }
+}
+
+impl Display for FilterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.position {
+            Some(position) => write!(
+                f,
+                "ошибка в фильтре, символ {}: {}",
+                position + 1,
+                self.message
+            ),
+            None => write!(f, "ошибка в фильтре: {}", self.message),
+        }
+    }
+}
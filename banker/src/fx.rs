@@ -0,0 +1,152 @@
+//! Модуль пересчитывает суммы операций в другую валюту по таблице курсов,
+//! например по ежедневным курсам центрального банка.
+//!
+//! Таблица курсов читается из `csv` с колонками `date`, `from`, `to` и `rate`:
+//! `rate` единиц `to` за одну единицу `from`. Если курса на дату операции нет,
+//! берётся курс за ближайшую предыдущую дату, но не раньше, чем за заданное число дней.
+//! Если есть только обратный курс, используется он.
+
+use crate::records::{CsvRecord, DATE_FORMAT, parse_date, to_cents};
+
+use chrono::{Days, NaiveDate};
+use serde::Deserialize;
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::Read;
+
+/// Применённый к операции курс. Записывается только в `csv` колонками
+/// `original_amount`, `original_currency`, `currency`, `rate` и `rate_date`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conversion {
+    /// Сумма до пересчёта.
+    pub original_amount: f64,
+    pub original_currency: String,
+    /// Валюта суммы после пересчёта.
+    pub currency: String,
+    pub rate: f64,
+    /// Дата курса, может быть раньше даты операции.
+    pub rate_date: String,
+}
+
+/// Курсы валютных пар по датам.
+#[derive(Debug, Clone, Default)]
+pub struct RateTable {
+    rates: HashMap<(String, String), BTreeMap<NaiveDate, f64>>,
+}
+
+#[derive(Deserialize)]
+struct RateRow {
+    date: String,
+    from: String,
+    to: String,
+    rate: f64,
+}
+
+impl RateTable {
+    /// Читает курсы из `csv` с заголовком `date,from,to,rate`.
+    /// Даты записываются как `2025-01-24` или `24-01-2025`.
+    /// Ошибка указывает строку файла с некорректным курсом или датой.
+    pub fn from_csv(reader: impl Read) -> Result<Self, String> {
+        let mut table = Self::default();
+        let mut csv_reader = csv::Reader::from_reader(reader);
+        for (index, row) in csv_reader.deserialize::<RateRow>().enumerate() {
+            // Строка 1 — заголовок.
+            let line = index + 2;
+            let row = row.map_err(|err| format!("строка {line}: {err}"))?;
+            let date = NaiveDate::parse_from_str(&row.date, "%Y-%m-%d")
+                .or_else(|_| parse_date(&row.date))
+                .map_err(|err| format!("строка {line}: некорректная дата `{}`: {err}", row.date))?;
+            if row.rate.is_nan() || row.rate <= 0.0 {
+                return Err(format!("строка {line}: курс должен быть больше нуля"));
+            }
+            table.insert(&row.from, &row.to, date, row.rate);
+        }
+        Ok(table)
+    }
+
+    /// Добавляет курс `from` к `to` на дату.
+    pub fn insert(&mut self, from: &str, to: &str, date: NaiveDate, rate: f64) {
+        self.rates
+            .entry((from.to_uppercase(), to.to_uppercase()))
+            .or_default()
+            .insert(date, rate);
+    }
+
+    /// Курс и его дата: на `date` или на ближайшую предыдущую дату не раньше
+    /// `fallback_days` дней назад. Курс валюты к самой себе равен 1.
+    pub fn rate(
+        &self,
+        from: &str,
+        to: &str,
+        date: NaiveDate,
+        fallback_days: u64,
+    ) -> Option<(NaiveDate, f64)> {
+        let (from, to) = (from.to_uppercase(), to.to_uppercase());
+        if from == to {
+            return Some((date, 1.0));
+        }
+
+        // Огромный запас дней не должен выходить за пределы дат chrono.
+        let earliest = date
+            .checked_sub_days(Days::new(fallback_days))
+            .unwrap_or(NaiveDate::MIN);
+        let latest = |rates: &BTreeMap<NaiveDate, f64>| {
+            rates
+                .range(earliest..=date)
+                .next_back()
+                .map(|(&date, &rate)| (date, rate))
+        };
+
+        let direct = self.rates.get(&(from.clone(), to.clone())).and_then(latest);
+        let inverse = self
+            .rates
+            .get(&(to, from))
+            .and_then(latest)
+            .map(|(date, rate)| (date, 1.0 / rate));
+        // Из прямого и обратного курса берётся более свежий.
+        match (direct, inverse) {
+            (Some(direct), Some(inverse)) if inverse.0 > direct.0 => Some(inverse),
+            (Some(direct), _) => Some(direct),
+            (None, inverse) => inverse,
+        }
+    }
+}
+
+/// Пересчитывает суммы операций из валюты `from` в `to` по курсу на дату операции,
+/// сумма округляется до копейки. Операции запоминают применённый курс в [CsvRecord::conversion].
+/// Ошибка, если у операции некорректная дата или курса не нашлось.
+pub fn convert(
+    records: Vec<CsvRecord>,
+    table: &RateTable,
+    from: &str,
+    to: &str,
+    fallback_days: u64,
+) -> Result<Vec<CsvRecord>, String> {
+    records
+        .into_iter()
+        .map(|mut record| {
+            let date = parse_date(&record.date).map_err(|err| {
+                format!(
+                    "операция `{}`: некорректная дата: {err}",
+                    record.transaction
+                )
+            })?;
+            let (rate_date, rate) = table.rate(from, to, date, fallback_days).ok_or_else(|| {
+                format!(
+                    "операция `{}`: нет курса {from}/{to} на {} и {fallback_days} дн. до неё",
+                    record.transaction, record.date
+                )
+            })?;
+
+            record.conversion = Some(Conversion {
+                original_amount: record.amount,
+                original_currency: from.to_uppercase(),
+                currency: to.to_uppercase(),
+                rate,
+                rate_date: rate_date.format(DATE_FORMAT).to_string(),
+            });
+            record.amount = to_cents(record.amount * rate) as f64 / 100.0;
+            Ok(record)
+        })
+        .collect()
+}
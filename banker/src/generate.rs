@@ -0,0 +1,336 @@
+//! Модуль генерирует правдоподобные операции для нагрузочных тестов: клиенты
+//! с русскими именами и названиями организаций, счета с корректным ключом по БИК,
+//! суммы по заданному распределению и даты в заданном периоде.
+//!
+//! Генератор детерминированный: одни и те же [GenerateOptions] всегда дают
+//! одни и те же операции. Случайные числа берутся из собственного генератора
+//! SplitMix64, поэтому результат не меняется и при обновлении зависимостей.
+
+use crate::records::{CsvRecord, DATE_FORMAT, parse_date, to_cents};
+use crate::validation;
+
+use chrono::Days;
+
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::str::FromStr;
+
+const FORMS: [&str; 3] = ["ООО", "АО", "ИП"];
+
+const COMPANIES: [&str; 16] = [
+    "Ромашка",
+    "Вектор",
+    "Северсталь-Сервис",
+    "Альфа Логистик",
+    "Техностиль",
+    "Глобус",
+    "Стройинвест",
+    "Меридиан",
+    "Агроторг",
+    "Полярная звезда",
+    "Импульс",
+    "Уральские самоцветы",
+    "Балтика Трейд",
+    "Сфера",
+    "Восход",
+    "Кедр",
+];
+
+const SURNAMES: [&str; 12] = [
+    "Иванов",
+    "Петров",
+    "Смирнов",
+    "Кузнецов",
+    "Попов",
+    "Соколов",
+    "Лебедев",
+    "Козлов",
+    "Новиков",
+    "Морозов",
+    "Волков",
+    "Фёдоров",
+];
+
+const NAMES: [&str; 10] = [
+    "Алексей",
+    "Дмитрий",
+    "Сергей",
+    "Андрей",
+    "Михаил",
+    "Иван",
+    "Николай",
+    "Артём",
+    "Павел",
+    "Роман",
+];
+
+/// БИК крупных банков, счета клиентов открываются в них.
+const BIKS: [&str; 6] = [
+    "044525225",
+    "044525593",
+    "044525974",
+    "044525187",
+    "044030653",
+    "046577964",
+];
+
+/// Распределение сумм операций.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Amounts {
+    /// Равномерно от `min` до `max`.
+    Uniform { min: f64, max: f64 },
+    /// Логнормально: половина сумм меньше `median`, `sigma` задаёт разброс.
+    /// Так распределены суммы настоящих платежей: много мелких и немного крупных.
+    LogNormal { median: f64, sigma: f64 },
+}
+
+impl Default for Amounts {
+    fn default() -> Self {
+        Amounts::LogNormal {
+            median: 5000.0,
+            sigma: 1.0,
+        }
+    }
+}
+
+impl Amounts {
+    /// Проверяет, что суммы положительные, а разброс не отрицательный.
+    pub fn validate(&self) -> Result<(), String> {
+        match *self {
+            Amounts::Uniform { min, max } => {
+                if min.is_nan() || min <= 0.0 || !max.is_finite() || min > max {
+                    return Err("для uniform нужно 0 < min <= max".into());
+                }
+            }
+            Amounts::LogNormal { median, sigma } => {
+                if median.is_nan() || median <= 0.0 || median.is_infinite() {
+                    return Err("медиана lognormal должна быть больше нуля".into());
+                }
+                if sigma.is_nan() || sigma < 0.0 || sigma.is_infinite() {
+                    return Err("разброс lognormal не может быть отрицательным".into());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Разбирает распределение вида `uniform:10:1000` или `lognormal:5000:1.2`.
+impl FromStr for Amounts {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(':').map(str::trim).collect();
+        let number = |value: &str| {
+            value
+                .parse::<f64>()
+                .map_err(|_| format!("`{value}` не число"))
+        };
+        let amounts = match parts.as_slice() {
+            ["uniform", min, max] => Amounts::Uniform {
+                min: number(min)?,
+                max: number(max)?,
+            },
+            ["lognormal", median, sigma] => Amounts::LogNormal {
+                median: number(median)?,
+                sigma: number(sigma)?,
+            },
+            _ => {
+                return Err(format!(
+                    "`{s}`: ожидается uniform:MIN:MAX или lognormal:MEDIAN:SIGMA"
+                ));
+            }
+        };
+        amounts.validate()?;
+        Ok(amounts)
+    }
+}
+
+impl Display for Amounts {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Amounts::Uniform { min, max } => write!(f, "uniform:{min}:{max}"),
+            Amounts::LogNormal { median, sigma } => write!(f, "lognormal:{median}:{sigma}"),
+        }
+    }
+}
+
+/// Настройки генератора.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenerateOptions {
+    /// Начальное значение генератора случайных чисел.
+    pub seed: u64,
+    /// Сколько операций сгенерировать.
+    pub count: usize,
+    /// Сколько разных клиентов участвуют в операциях, не меньше 2.
+    pub clients: usize,
+    pub amounts: Amounts,
+    /// Первая дата операций в формате `дд-мм-гггг`.
+    pub from: String,
+    /// Последняя дата операций включительно.
+    pub to: String,
+}
+
+impl Default for GenerateOptions {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            count: 100,
+            clients: 100,
+            amounts: Amounts::default(),
+            from: "01-01-2025".into(),
+            to: "31-12-2025".into(),
+        }
+    }
+}
+
+/// Генерирует операции, отсортированные по дате. Номера транзакций идут по порядку с 1.
+/// Ошибка, если настройки некорректны.
+pub fn generate(options: &GenerateOptions) -> Result<Vec<CsvRecord>, String> {
+    options.amounts.validate()?;
+    if options.clients < 2 {
+        return Err("нужно хотя бы два клиента".into());
+    }
+    let from = parse_date(&options.from)
+        .map_err(|err| format!("некорректная дата `{}`: {err}", options.from))?;
+    let to = parse_date(&options.to)
+        .map_err(|err| format!("некорректная дата `{}`: {err}", options.to))?;
+    if from > to {
+        return Err(format!(
+            "первая дата {} позже последней {}",
+            options.from, options.to
+        ));
+    }
+    let days = (to - from).num_days() as u64 + 1;
+
+    let mut rng = Rng(options.seed);
+    let clients = clients(&mut rng, options.clients);
+
+    let mut records: Vec<(u64, CsvRecord)> = (0..options.count)
+        .map(|_| {
+            let (sender, receiver) = counterparties(&mut rng, clients.len());
+            let day = rng.below(days);
+            let amount = amount(&mut rng, &options.amounts);
+            let record = CsvRecord::new(
+                clients[sender].0.clone(),
+                clients[sender].1.clone(),
+                clients[receiver].0.clone(),
+                clients[receiver].1.clone(),
+                String::new(),
+                amount,
+                String::new(),
+            );
+            (day, record)
+        })
+        .collect();
+
+    records.sort_by_key(|(day, _)| *day);
+    Ok(records
+        .into_iter()
+        .enumerate()
+        .map(|(index, (day, mut record))| {
+            record.transaction = (index + 1).to_string();
+            record.date = (from + Days::new(day)).format(DATE_FORMAT).to_string();
+            record
+        })
+        .collect())
+}
+
+/// Клиенты с уникальными именами и реквизитами `БИК/счёт`.
+fn clients(rng: &mut Rng, count: usize) -> Vec<(String, String)> {
+    let mut names = HashSet::new();
+    (0..count)
+        .map(|index| {
+            let (mut name, prefix) = client(rng);
+            if !names.insert(name.clone()) {
+                name = format!("{name} {}", index + 1);
+                names.insert(name.clone());
+            }
+            let bik = BIKS[rng.below(BIKS.len() as u64) as usize];
+            (name, format!("{bik}/{}", account(rng, bik, prefix)))
+        })
+        .collect()
+}
+
+/// Имя клиента и начало номера его счёта: `40817` у физических лиц, `40802` у ИП,
+/// `40702` у организаций.
+fn client(rng: &mut Rng) -> (String, &'static str) {
+    let surname = SURNAMES[rng.below(SURNAMES.len() as u64) as usize];
+    let name = NAMES[rng.below(NAMES.len() as u64) as usize];
+    // Примерно половина клиентов — организации и ИП.
+    if rng.below(2) == 0 {
+        return (format!("{surname} {name}"), "40817810");
+    }
+    match FORMS[rng.below(FORMS.len() as u64) as usize] {
+        "ИП" => (format!("ИП {surname} {name}"), "40802810"),
+        form => (
+            format!(
+                "{form} «{}»",
+                COMPANIES[rng.below(COMPANIES.len() as u64) as usize]
+            ),
+            "40702810",
+        ),
+    }
+}
+
+/// Счёт с корректным ключом по БИК.
+fn account(rng: &mut Rng, bik: &str, prefix: &str) -> String {
+    let rest: String = (0..11)
+        .map(|_| char::from(b'0' + rng.below(10) as u8))
+        .collect();
+    (0..10)
+        .map(|key| format!("{prefix}{key}{rest}"))
+        .find(|account| validation::ru_account(bik, account).is_ok())
+        .expect("одна из 10 цифр ключа подходит")
+}
+
+/// Отправитель и получатель, разные клиенты. Клиенты в начале списка
+/// встречаются чаще, как самые активные контрагенты.
+fn counterparties(rng: &mut Rng, count: usize) -> (usize, usize) {
+    let pick = |rng: &mut Rng| ((rng.unit().powi(2) * count as f64) as usize).min(count - 1);
+    let sender = pick(rng);
+    let mut receiver = pick(rng);
+    if receiver == sender {
+        receiver = (sender + 1 + rng.below(count as u64 - 1) as usize) % count;
+    }
+    (sender, receiver)
+}
+
+/// Сумма по распределению, округлённая до копейки, не меньше копейки.
+fn amount(rng: &mut Rng, amounts: &Amounts) -> f64 {
+    let amount = match *amounts {
+        Amounts::Uniform { min, max } => min + rng.unit() * (max - min),
+        Amounts::LogNormal { median, sigma } => median * (sigma * rng.normal()).exp(),
+    };
+    to_cents(amount).max(1) as f64 / 100.0
+}
+
+/// Генератор случайных чисел SplitMix64.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Число от 0 включительно до 1.
+    fn unit(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Число от 0 включительно до `n`.
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    /// Стандартное нормальное распределение по Боксу — Мюллеру.
+    fn normal(&mut self) -> f64 {
+        let u = 1.0 - self.unit();
+        let v = self.unit();
+        (-2.0 * u.ln()).sqrt() * (2.0 * std::f64::consts::PI * v).cos()
+    }
+}
@@ -1,10 +1,29 @@
 #![doc = include_str!("../README.md")]
 
+pub mod anonymize;
+pub mod categorize;
+pub mod dedupe;
+pub mod diff;
+pub mod direction;
 pub mod error;
+pub mod filter;
+pub mod fx;
+pub mod generate;
+pub mod merge;
+pub mod reconcile;
 pub mod records;
+pub mod rules;
+pub mod sort;
+pub mod split;
+pub mod statement;
+pub mod stats;
+pub mod validation;
 
+#[cfg(feature = "columnar")]
+mod columnar;
 mod parsers;
 mod printers;
+mod value;
 
 use error::BankError;
 use records::{CsvRecord, CsvRecords, JsonRecord, JsonRecords};
@@ -49,15 +68,7 @@ where
 /// use banker::convert_to;
 /// use banker::records::{CsvRecord, CsvRecords, JsonRecords};
 ///
-/// let record = CsvRecord {
-///     from_client: "Alice".into(),
-///     from_bank: "bank_a".into(),
-///     to_client: "Bob".into(),
-///     to_bank: "bank_b".into(),
-///     transaction: "123".into(),
-///     amount: 500.05,
-///     date: "24-01-2025".into(),
-/// };
+/// let record = CsvRecord::new("Alice", "bank_a", "Bob", "bank_b", "123", 500.05, "24-01-2025");
 /// let csv: CsvRecords = vec![record].into();
 ///
 /// let json: JsonRecords = convert_to(csv);
@@ -76,15 +87,7 @@ where
 /// use banker::print;
 /// use banker::records::{CsvRecord, CsvRecords};
 ///
-/// let record = CsvRecord {
-///     from_client: "Alice".into(),
-///     from_bank: "bank_a".into(),
-///     to_client: "Bob".into(),
-///     to_bank: "bank_b".into(),
-///     transaction: "123".into(),
-///     amount: 500.05,
-///     date: "24-01-2025".into(),
-/// };
+/// let record = CsvRecord::new("Alice", "bank_a", "Bob", "bank_b", "123", 500.05, "24-01-2025");
 /// let records: CsvRecords = vec![record].into();
 ///
 /// let mut buffer = Vec::new();
@@ -102,5 +105,29 @@ where
     Ok(records.print(writer)?)
 }
 
+/// Проверяет реквизиты банков отправителя и получателя: IBAN, BIC, БИК или пару `БИК/счёт`.
+/// Возвращает список найденных проблем, пустой, если всё в порядке.
+///
+/// # Пример
+///
+/// ```
+/// use banker::records::CsvRecord;
+/// use banker::validate;
+///
+/// let record = CsvRecord::new(
+///     "Alice",
+///     "DE89370400440532013000",
+///     "Bob",
+///     "DEUTDEFF",
+///     "123",
+///     500.05,
+///     "24-01-2025",
+/// );
+///
+/// assert!(validate(&[record]).is_empty());
+pub fn validate(records: &[CsvRecord]) -> Vec<validation::Issue> {
+    validation::records(records)
+}
+
 #[cfg(test)]
 mod tests;
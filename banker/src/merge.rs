@@ -0,0 +1,37 @@
+//! Модуль объединяет операции из нескольких источников, например из ежедневных
+//! выписок за месяц, в один список.
+//!
+//! Операции можно пометить источником ([tag]), тогда при записи в `csv` видно,
+//! из какого файла и какой по счёту операции в нём пришла каждая строка.
+
+use crate::records::{CsvRecord, Source};
+use crate::sort::{self, SortKey};
+
+/// Помечает операции источником: файлом `file` и номером операции в нём, начиная с 1.
+pub fn tag(records: Vec<CsvRecord>, file: &str) -> Vec<CsvRecord> {
+    records
+        .into_iter()
+        .enumerate()
+        .map(|(index, mut record)| {
+            record.source = Some(Source {
+                file: file.into(),
+                row: index + 1,
+            });
+            record
+        })
+        .collect()
+}
+
+/// Объединяет списки операций в порядке источников.
+///
+/// С `by_date` операции упорядочиваются по дате, операции с одной датой остаются
+/// в порядке источников, поэтому уже упорядоченные выписки сливаются без перемешивания.
+/// Ошибка, если при упорядочивании у операции некорректная дата.
+pub fn merge(sources: Vec<Vec<CsvRecord>>, by_date: bool) -> Result<Vec<CsvRecord>, String> {
+    let records: Vec<CsvRecord> = sources.into_iter().flatten().collect();
+    if by_date {
+        sort::sort(records, &[SortKey::asc("date")])
+    } else {
+        Ok(records)
+    }
+}
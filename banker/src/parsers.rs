@@ -1,15 +1,47 @@
 //! Модуль для парсинга данных.
 
+#[cfg(feature = "columnar")]
+pub mod arrow_parser;
+#[cfg(feature = "cbor")]
+pub mod cbor_parser;
 pub mod csv_parser;
+pub mod debit_credit_parser;
+pub mod fixed_parser;
 pub mod json_parser;
+#[cfg(feature = "msgpack")]
+pub mod msgpack_parser;
+#[cfg(feature = "columnar")]
+pub mod parquet_parser;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_parser;
+pub mod toml_parser;
+pub mod xml_parser;
+pub mod yaml_parser;
 
 use std::error::Error;
 use std::fmt;
 
 #[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum ParseError {
     CsvParseError(csv::Error),
     JsonParseError(serde_json::Error),
+    XmlParseError(String),
+    FixedWidthParseError(String),
+    DebitCreditParseError(String),
+    RulesParseError(String),
+    YamlParseError(serde_yaml_ng::Error),
+    TomlParseError(String),
+    #[cfg(feature = "columnar")]
+    ParquetParseError(parquet::errors::ParquetError),
+    #[cfg(feature = "columnar")]
+    ArrowParseError(arrow::error::ArrowError),
+    #[cfg(feature = "sqlite")]
+    SqliteParseError(rusqlite::Error),
+    #[cfg(feature = "msgpack")]
+    MsgpackParseError(rmp_serde::decode::Error),
+    #[cfg(feature = "cbor")]
+    CborParseError(ciborium::de::Error<std::io::Error>),
 }
 
 impl fmt::Display for ParseError {
@@ -18,10 +50,48 @@ impl fmt::Display for ParseError {
 
         match self {
             CsvParseError(error) => {
-                write!(f, "{}", error.to_string())
+                write!(f, "{}", error)
             }
             JsonParseError(error) => {
-                write!(f, "{}", error.to_string())
+                write!(f, "{}", error)
+            }
+            XmlParseError(error) => {
+                write!(f, "{}", error)
+            }
+            FixedWidthParseError(error) => {
+                write!(f, "{}", error)
+            }
+            DebitCreditParseError(error) => {
+                write!(f, "{}", error)
+            }
+            RulesParseError(error) => {
+                write!(f, "{}", error)
+            }
+            YamlParseError(error) => {
+                write!(f, "{}", error)
+            }
+            TomlParseError(error) => {
+                write!(f, "{}", error)
+            }
+            #[cfg(feature = "columnar")]
+            ParquetParseError(error) => {
+                write!(f, "{}", error)
+            }
+            #[cfg(feature = "columnar")]
+            ArrowParseError(error) => {
+                write!(f, "{}", error)
+            }
+            #[cfg(feature = "sqlite")]
+            SqliteParseError(error) => {
+                write!(f, "{}", error)
+            }
+            #[cfg(feature = "msgpack")]
+            MsgpackParseError(error) => {
+                write!(f, "{}", error)
+            }
+            #[cfg(feature = "cbor")]
+            CborParseError(error) => {
+                write!(f, "{}", error)
             }
         }
     }
@@ -0,0 +1,33 @@
+//! Модуль предоставляет функционал для парсинга `arrow ipc` в структуру [ArrowRecords].
+
+use super::ParseError;
+use crate::columnar;
+use crate::records::ArrowRecords;
+
+use arrow::error::ArrowError;
+use arrow::ipc::reader::FileReader;
+
+use std::io::{Cursor, Read};
+
+/// Парсит в [ArrowRecords] структуру, реализующую трейт [Read].
+///
+/// Формат требует произвольного доступа к файлу, поэтому данные сначала читаются целиком.
+pub fn parse(mut reader: impl Read) -> Result<ArrowRecords, ParseError> {
+    let mut buffer = Vec::new();
+    reader.read_to_end(&mut buffer).map_err(ArrowError::from)?;
+
+    let batches = FileReader::try_new(Cursor::new(buffer), None)?;
+
+    let mut records = Vec::new();
+    for batch in batches {
+        records.extend(columnar::from_batch(&batch?)?);
+    }
+
+    Ok(records.into())
+}
+
+impl From<ArrowError> for ParseError {
+    fn from(value: ArrowError) -> Self {
+        ParseError::ArrowParseError(value)
+    }
+}
@@ -0,0 +1,19 @@
+//! Модуль предоставляет функционал для парсинга `cbor` в структуру [CborRecords].
+
+use super::ParseError;
+use crate::CsvRecord;
+use crate::records::CborRecords;
+
+use std::io::{self, Read};
+
+/// Парсит в [CborRecords] структуру, реализующую трейт [Read].
+pub fn parse(reader: impl Read) -> Result<CborRecords, ParseError> {
+    let records: Vec<CsvRecord> = ciborium::from_reader(reader)?;
+    Ok(records.into())
+}
+
+impl From<ciborium::de::Error<io::Error>> for ParseError {
+    fn from(value: ciborium::de::Error<io::Error>) -> Self {
+        ParseError::CborParseError(value)
+    }
+}
@@ -0,0 +1,45 @@
+//! Модуль предоставляет функционал для парсинга `csv` с колонками списаний
+//! и поступлений в структуру [DebitCreditRecords].
+
+use super::ParseError;
+use crate::CsvRecord;
+use crate::records::{DebitCreditRecords, DebitCreditRow};
+
+use std::io::Read;
+
+/// Парсит в [DebitCreditRecords] структуру, реализующую трейт [Read].
+pub fn parse(reader: impl Read) -> Result<DebitCreditRecords, ParseError> {
+    let mut reader = csv::Reader::from_reader(reader);
+
+    let mut records: Vec<CsvRecord> = Vec::new();
+    for row in reader.deserialize() {
+        let row: DebitCreditRow = row?;
+        let amount = match (row.debit, row.credit) {
+            (Some(amount), None) | (None, Some(amount)) => amount,
+            _ => {
+                return Err(ParseError::DebitCreditParseError(format!(
+                    "у операции `{}` должна быть заполнена ровно одна из колонок debit и credit",
+                    row.transaction
+                )));
+            }
+        };
+
+        records.push(CsvRecord {
+            description: row.description,
+            end_to_end_id: row.end_to_end_id,
+            remittance_reference: row.remittance_reference,
+            value_date: row.value_date,
+            ..CsvRecord::new(
+                row.from_client,
+                row.from_bank,
+                row.to_client,
+                row.to_bank,
+                row.transaction,
+                amount,
+                row.date,
+            )
+        });
+    }
+
+    Ok(records.into())
+}
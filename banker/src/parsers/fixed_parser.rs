@@ -0,0 +1,100 @@
+//! Модуль предоставляет функционал для парсинга позиционного формата в структуру [FixedRecords].
+
+use super::ParseError;
+use crate::CsvRecord;
+use crate::records::{Align, DATE_FORMAT, FixedColumn, FixedLayout, FixedRecords};
+
+use chrono::NaiveDate;
+
+use std::io::{BufRead, BufReader, Read};
+
+/// Парсит в [FixedRecords] структуру, реализующую трейт [Read], по описанию колонок
+/// из [FixedLayout]. Пустые строки пропускаются, пустые колонки необязательных полей
+/// оставляют поле незаполненным.
+pub fn parse(reader: impl Read, layout: FixedLayout) -> Result<FixedRecords, ParseError> {
+    layout
+        .validate()
+        .map_err(ParseError::FixedWidthParseError)?;
+
+    let mut records = Vec::new();
+    for (number, line) in BufReader::new(reader).lines().enumerate() {
+        let line = line.map_err(|err| ParseError::FixedWidthParseError(err.to_string()))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let record = parse_line(&line, &layout).map_err(|err| {
+            ParseError::FixedWidthParseError(format!("строка {}: {err}", number + 1))
+        })?;
+        records.push(record);
+    }
+
+    Ok(FixedRecords::from(records).with_layout(layout))
+}
+
+fn parse_line(line: &str, layout: &FixedLayout) -> Result<CsvRecord, String> {
+    let chars: Vec<char> = line.trim_end_matches('\r').chars().collect();
+    let value = |field: &str| {
+        let column = layout.columns.iter().find(|c| c.field == field).unwrap();
+        (column, cut(&chars, column))
+    };
+
+    let (column, amount) = value("amount");
+    let amount = parse_amount(&amount, column.decimals)?;
+
+    let (column, date) = value("date");
+    let date = match &column.date_format {
+        Some(format) => NaiveDate::parse_from_str(&date, format)
+            .map_err(|err| format!("некорректная дата `{date}`: {err}"))?
+            .format(DATE_FORMAT)
+            .to_string(),
+        None => date,
+    };
+
+    let mut record = CsvRecord::new(
+        value("from_client").1,
+        value("from_bank").1,
+        value("to_client").1,
+        value("to_bank").1,
+        value("transaction").1,
+        amount,
+        date,
+    );
+    for column in &layout.columns {
+        if let Some(detail) = record.detail_mut(&column.field) {
+            *detail = Some(cut(&chars, column)).filter(|value| !value.is_empty());
+        }
+    }
+
+    Ok(record)
+}
+
+/// Вырезает значение колонки и убирает заполнитель со стороны, противоположной выравниванию.
+fn cut(chars: &[char], column: &FixedColumn) -> String {
+    let start = (column.start - 1).min(chars.len());
+    let end = (start + column.width).min(chars.len());
+    let value: String = chars[start..end].iter().collect();
+
+    let value = match column.align {
+        Align::Left => value.trim_end_matches(column.pad),
+        Align::Right => value.trim_start_matches(column.pad),
+    };
+    value.trim().to_string()
+}
+
+fn parse_amount(amount: &str, decimals: Option<u32>) -> Result<f64, String> {
+    let invalid = |err: &dyn std::fmt::Display| format!("некорректная сумма `{amount}`: {err}");
+
+    match decimals {
+        Some(decimals) => {
+            let amount = match amount {
+                "" | "-" => "0",
+                amount => amount,
+            };
+            let units: i64 = amount.parse().map_err(|err| invalid(&err))?;
+            Ok(units as f64 / 10f64.powi(decimals as i32))
+        }
+        None if amount.is_empty() => Ok(0.0),
+        None => amount.parse().map_err(|err| invalid(&err)),
+    }
+}
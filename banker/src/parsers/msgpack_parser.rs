@@ -0,0 +1,19 @@
+//! Модуль предоставляет функционал для парсинга `msgpack` в структуру [MsgpackRecords].
+
+use super::ParseError;
+use crate::CsvRecord;
+use crate::records::MsgpackRecords;
+
+use std::io::Read;
+
+/// Парсит в [MsgpackRecords] структуру, реализующую трейт [Read].
+pub fn parse(reader: impl Read) -> Result<MsgpackRecords, ParseError> {
+    let records: Vec<CsvRecord> = rmp_serde::from_read(reader)?;
+    Ok(records.into())
+}
+
+impl From<rmp_serde::decode::Error> for ParseError {
+    fn from(value: rmp_serde::decode::Error) -> Self {
+        ParseError::MsgpackParseError(value)
+    }
+}
@@ -0,0 +1,36 @@
+//! Модуль предоставляет функционал для парсинга `parquet` в структуру [ParquetRecords].
+
+use super::ParseError;
+use crate::columnar;
+use crate::records::ParquetRecords;
+
+use bytes::Bytes;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::errors::ParquetError;
+
+use std::io::Read;
+
+/// Парсит в [ParquetRecords] структуру, реализующую трейт [Read].
+///
+/// Формат требует произвольного доступа к файлу, поэтому данные сначала читаются целиком.
+pub fn parse(mut reader: impl Read) -> Result<ParquetRecords, ParseError> {
+    let mut buffer = Vec::new();
+    reader
+        .read_to_end(&mut buffer)
+        .map_err(ParquetError::from)?;
+
+    let batches = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer))?.build()?;
+
+    let mut records = Vec::new();
+    for batch in batches {
+        records.extend(columnar::from_batch(&batch?)?);
+    }
+
+    Ok(records.into())
+}
+
+impl From<ParquetError> for ParseError {
+    fn from(value: ParquetError) -> Self {
+        ParseError::ParquetParseError(value)
+    }
+}
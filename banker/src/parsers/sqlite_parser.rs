@@ -0,0 +1,84 @@
+//! Модуль предоставляет функционал для чтения операций из `sqlite` в структуру [SqliteRecords].
+
+use super::ParseError;
+use crate::CsvRecord;
+use crate::records::{DATE_FORMAT, DETAILS, SQLITE_TABLE, SqliteRecords};
+
+use chrono::NaiveDate;
+use rusqlite::{Connection, OpenFlags};
+
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Читает в [SqliteRecords] операции из таблицы [SQLITE_TABLE], подходящие под условие `filter`.
+/// Если в таблице нет колонок необязательных полей из [DETAILS], поля остаются незаполненными.
+pub fn parse(path: &Path, filter: Option<&str>) -> Result<SqliteRecords, ParseError> {
+    let connection = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+
+    let existing = columns(&connection)?;
+    let details: Vec<String> = DETAILS
+        .iter()
+        .map(|&detail| match existing.contains(detail) {
+            true => detail.to_string(),
+            false => format!("NULL AS {detail}"),
+        })
+        .collect();
+
+    let mut query = format!(
+        "SELECT from_client, from_bank, to_client, to_bank, transaction_id, amount, date, {} \
+        FROM {SQLITE_TABLE}",
+        details.join(", ")
+    );
+    if let Some(filter) = filter {
+        query.push_str(&format!(" WHERE {filter}"));
+    }
+    query.push_str(" ORDER BY id");
+
+    let mut statement = connection.prepare(&query)?;
+    let rows = statement.query_map([], |row| {
+        Ok(CsvRecord {
+            description: row.get(7)?,
+            end_to_end_id: row.get(8)?,
+            remittance_reference: row.get(9)?,
+            value_date: row.get(10)?,
+            ..CsvRecord::new(
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(4)?,
+                row.get(5)?,
+                row.get::<_, String>(6)?,
+            )
+        })
+    })?;
+
+    let mut records = Vec::new();
+    for row in rows {
+        let mut record = row?;
+        record.date = from_iso(record.date);
+        record.value_date = record.value_date.map(from_iso);
+        records.push(record);
+    }
+
+    Ok(records.into())
+}
+
+/// Названия колонок таблицы [SQLITE_TABLE].
+pub(crate) fn columns(connection: &Connection) -> Result<HashSet<String>, rusqlite::Error> {
+    let mut statement = connection.prepare(&format!("PRAGMA table_info({SQLITE_TABLE})"))?;
+    let names = statement.query_map([], |row| row.get::<_, String>(1))?;
+    names.collect()
+}
+
+fn from_iso(date: String) -> String {
+    NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+        .map(|date| date.format(DATE_FORMAT).to_string())
+        .unwrap_or(date)
+}
+
+impl From<rusqlite::Error> for ParseError {
+    fn from(value: rusqlite::Error) -> Self {
+        ParseError::SqliteParseError(value)
+    }
+}
@@ -0,0 +1,25 @@
+//! Модуль предоставляет функционал для парсинга `toml` в структуру [TomlRecords].
+
+use super::ParseError;
+use crate::CsvRecord;
+use crate::records::{TomlDocument, TomlRecords};
+
+use std::io::Read;
+
+/// Парсит в [TomlRecords] структуру, реализующую трейт [Read].
+/// Документ без таблиц `[[transaction]]` считается пустым списком.
+pub fn parse(mut reader: impl Read) -> Result<TomlRecords, ParseError> {
+    let mut document = String::new();
+    reader
+        .read_to_string(&mut document)
+        .map_err(|err| ParseError::TomlParseError(err.to_string()))?;
+
+    let document: TomlDocument<Vec<CsvRecord>> = toml::from_str(&document)?;
+    Ok(document.transaction.into())
+}
+
+impl From<toml::de::Error> for ParseError {
+    fn from(value: toml::de::Error) -> Self {
+        ParseError::TomlParseError(value.to_string())
+    }
+}
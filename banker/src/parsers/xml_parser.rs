@@ -0,0 +1,165 @@
+//! Модуль предоставляет функционал для парсинга `xml` в структуру [XmlRecords].
+
+use super::ParseError;
+use crate::CsvRecord;
+use crate::records::{DETAILS, FIELDS, XmlOptions, XmlRecords};
+
+use quick_xml::Reader;
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::Event;
+
+use std::collections::HashMap;
+use std::io::{BufReader, Read};
+
+/// Парсит в [XmlRecords] структуру, реализующую трейт [Read], ожидая названия
+/// элементов из [XmlOptions]. Элементы необязательных полей из [DETAILS] могут отсутствовать.
+pub fn parse(reader: impl Read, options: XmlOptions) -> Result<XmlRecords, ParseError> {
+    options.validate().map_err(ParseError::XmlParseError)?;
+
+    let fields: HashMap<&str, &str> = FIELDS
+        .iter()
+        .chain(&DETAILS)
+        .map(|&field| (options.element(field), field))
+        .collect();
+
+    let mut reader = Reader::from_reader(BufReader::new(reader));
+    let mut buffer = Vec::new();
+
+    let mut path: Vec<String> = Vec::new();
+    let mut values: HashMap<&str, String> = HashMap::new();
+    let mut text = String::new();
+    let mut records = Vec::new();
+
+    loop {
+        let event = reader.read_event_into(&mut buffer)?;
+        let depth = path.len();
+
+        match event {
+            Event::Start(ref element) | Event::Empty(ref element) => {
+                let name = String::from_utf8_lossy(element.name().as_ref()).into_owned();
+                let expected = match depth {
+                    0 => Some(options.root.as_str()),
+                    1 => Some(options.record.as_str()),
+                    2 => fields.contains_key(name.as_str()).then_some(name.as_str()),
+                    _ => None,
+                };
+                if expected != Some(name.as_str()) {
+                    return Err(unexpected(&name, reader.buffer_position()));
+                }
+
+                text.clear();
+                path.push(name);
+                if let Event::Empty(_) = event {
+                    close(&mut path, &mut values, &mut text, &mut records, &fields)?;
+                }
+            }
+            Event::End(_) => {
+                close(&mut path, &mut values, &mut text, &mut records, &fields)?;
+            }
+            Event::Text(content) if depth == 3 => text.push_str(&content.xml_content()?),
+            Event::CData(content) if depth == 3 => text.push_str(&content.decode()?),
+            Event::GeneralRef(reference) if depth == 3 => {
+                let resolved = match reference.resolve_char_ref()? {
+                    Some(c) => c.to_string(),
+                    None => {
+                        let name = reference.decode()?;
+                        resolve_predefined_entity(&name)
+                            .ok_or_else(|| {
+                                ParseError::XmlParseError(format!(
+                                    "неизвестная сущность `&{name};`"
+                                ))
+                            })?
+                            .to_string()
+                    }
+                };
+                text.push_str(&resolved);
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+
+        buffer.clear();
+    }
+
+    if !path.is_empty() {
+        return Err(ParseError::XmlParseError(format!(
+            "не закрыт элемент `{}`",
+            path.last().unwrap()
+        )));
+    }
+
+    Ok(records.into())
+}
+
+/// Закрывает текущий элемент: поле запоминается, операция собирается из полей.
+/// Ошибка, если поле в операции повторяется.
+fn close<'a>(
+    path: &mut Vec<String>,
+    values: &mut HashMap<&'a str, String>,
+    text: &mut String,
+    records: &mut Vec<CsvRecord>,
+    fields: &HashMap<&str, &'a str>,
+) -> Result<(), ParseError> {
+    let name = path.pop().unwrap_or_default();
+
+    match path.len() {
+        2 => {
+            let field = fields[name.as_str()];
+            if values.insert(field, text.trim().to_string()).is_some() {
+                return Err(ParseError::XmlParseError(format!(
+                    "поле `{field}` задано несколько раз"
+                )));
+            }
+        }
+        1 => records.push(record(values)?),
+        _ => {}
+    }
+
+    text.clear();
+    Ok(())
+}
+
+fn record(values: &mut HashMap<&str, String>) -> Result<CsvRecord, ParseError> {
+    let mut take = |field: &str| {
+        values
+            .remove(field)
+            .ok_or_else(|| ParseError::XmlParseError(format!("отсутствует поле `{field}`")))
+    };
+
+    let amount = take("amount")?;
+    let mut record = CsvRecord::new(
+        take("from_client")?,
+        take("from_bank")?,
+        take("to_client")?,
+        take("to_bank")?,
+        take("transaction")?,
+        amount.parse().map_err(|err| {
+            ParseError::XmlParseError(format!("некорректная сумма `{amount}`: {err}"))
+        })?,
+        take("date")?,
+    );
+    for detail in DETAILS {
+        *record.detail_mut(detail).unwrap() = values.remove(detail);
+    }
+
+    values.clear();
+    Ok(record)
+}
+
+fn unexpected(name: &str, position: u64) -> ParseError {
+    ParseError::XmlParseError(format!(
+        "неожиданный элемент `{name}` на позиции {position}"
+    ))
+}
+
+impl From<quick_xml::Error> for ParseError {
+    fn from(value: quick_xml::Error) -> Self {
+        ParseError::XmlParseError(value.to_string())
+    }
+}
+
+impl From<quick_xml::encoding::EncodingError> for ParseError {
+    fn from(value: quick_xml::encoding::EncodingError) -> Self {
+        ParseError::XmlParseError(value.to_string())
+    }
+}
@@ -0,0 +1,19 @@
+//! Модуль предоставляет функционал для парсинга `yaml` в структуру [YamlRecords].
+
+use super::ParseError;
+use crate::CsvRecord;
+use crate::records::YamlRecords;
+
+use std::io::Read;
+
+/// Парсит в [YamlRecords] структуру, реализующую трейт [Read].
+pub fn parse(reader: impl Read) -> Result<YamlRecords, ParseError> {
+    let records: Vec<CsvRecord> = serde_yaml_ng::from_reader(reader)?;
+    Ok(records.into())
+}
+
+impl From<serde_yaml_ng::Error> for ParseError {
+    fn from(value: serde_yaml_ng::Error) -> Self {
+        ParseError::YamlParseError(value)
+    }
+}
@@ -1,4 +1,27 @@
 //! Модуль для записи данных.
 
+#[cfg(feature = "columnar")]
+pub mod arrow_printer;
+pub mod beancount_printer;
+#[cfg(feature = "cbor")]
+pub mod cbor_printer;
 pub mod csv_printer;
+pub mod debit_credit_printer;
+pub mod diff_printer;
+pub mod fixed_printer;
+pub mod html_printer;
 pub mod json_printer;
+pub mod ledger_printer;
+pub mod markdown_printer;
+#[cfg(feature = "msgpack")]
+pub mod msgpack_printer;
+#[cfg(feature = "columnar")]
+pub mod parquet_printer;
+pub mod reconcile_printer;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_printer;
+pub mod stats_printer;
+pub mod table_printer;
+pub mod toml_printer;
+pub mod xml_printer;
+pub mod yaml_printer;
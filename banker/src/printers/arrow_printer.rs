@@ -0,0 +1,50 @@
+//! Модуль предоставляет функционал для записи [ArrowRecords].
+
+use crate::columnar::{self, Compression};
+use crate::records::ArrowRecords;
+
+use arrow::error::ArrowError;
+use arrow::ipc::CompressionType;
+use arrow::ipc::writer::{FileWriter, IpcWriteOptions};
+
+use std::io::{self, Write};
+
+/// Записывает [ArrowRecords] в структуру, реализующую трейт [Write].
+pub fn print(writer: impl Write, records: &ArrowRecords) -> Result<(), io::Error> {
+    let options = records.options();
+
+    let write_options = IpcWriteOptions::default()
+        .try_with_compression(compression(options.compression)?)
+        .map_err(io::Error::other)?;
+
+    let batch = columnar::to_batch(records.list()).map_err(io::Error::other)?;
+
+    let mut arrow_writer = FileWriter::try_new_with_options(writer, &batch.schema(), write_options)
+        .map_err(io::Error::other)?;
+
+    let batch_size = options.batch_size.max(1);
+    let mut offset = 0;
+    while offset < batch.num_rows() {
+        let length = batch_size.min(batch.num_rows() - offset);
+        arrow_writer
+            .write(&batch.slice(offset, length))
+            .map_err(io::Error::other)?;
+        offset += length;
+    }
+
+    arrow_writer.finish().map_err(io::Error::other)
+}
+
+fn compression(compression: Compression) -> Result<Option<CompressionType>, io::Error> {
+    match compression {
+        Compression::None => Ok(None),
+        Compression::Lz4 => Ok(Some(CompressionType::LZ4_FRAME)),
+        Compression::Zstd => Ok(Some(CompressionType::ZSTD)),
+        Compression::Snappy | Compression::Gzip => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            ArrowError::InvalidArgumentError(format!(
+                "сжатие {compression:?} не поддерживается форматом arrow ipc"
+            )),
+        )),
+    }
+}
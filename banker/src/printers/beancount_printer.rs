@@ -0,0 +1,79 @@
+//! Модуль предоставляет функционал для записи [BeancountRecords] в журнал Beancount.
+
+use crate::records::{BeancountRecords, Syntax, journal_amount, journal_date, rate_date};
+
+use chrono::NaiveDate;
+
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+/// Записывает [BeancountRecords] в структуру, реализующую трейт [Write].
+///
+/// Перед транзакциями записываются директивы `open` для всех задействованных счетов,
+/// датированные первой операцией по счёту. Валюта суммы берётся из пересчёта операции,
+/// если он был, иначе из [crate::records::JournalOptions]. Исходная сумма и применённый
+/// курс записываются метаданными транзакции.
+pub fn print(mut writer: impl Write, records: &BeancountRecords) -> Result<(), io::Error> {
+    let options = records.options();
+
+    let mut transactions = Vec::new();
+    let mut opened: BTreeMap<String, NaiveDate> = BTreeMap::new();
+
+    for record in records.list() {
+        let date = journal_date(record)?;
+        let (from, to) = options.accounts(record, Syntax::Beancount);
+
+        for account in [&from, &to] {
+            let opened_at = opened.entry(account.clone()).or_insert(date);
+            *opened_at = date.min(*opened_at);
+        }
+        transactions.push((date, record, from, to));
+    }
+
+    for (account, date) in &opened {
+        writeln!(writer, "{date} open {account}")?;
+    }
+
+    for (date, record, from, to) in transactions {
+        writeln!(writer)?;
+        writeln!(
+            writer,
+            "{date} * \"{}\" \"{} -> {}\"",
+            escape(&record.from_client),
+            escape(&record.from_client),
+            escape(&record.to_client)
+        )?;
+        writeln!(writer, "  transaction: \"{}\"", escape(&record.transaction))?;
+        if let Some(conversion) = &record.conversion {
+            writeln!(
+                writer,
+                "  original_amount: {} {}",
+                journal_amount(conversion.original_amount),
+                conversion.original_currency.to_uppercase()
+            )?;
+            writeln!(writer, "  rate: {}", conversion.rate)?;
+            writeln!(writer, "  rate_date: {}", rate_date(&conversion.rate_date))?;
+        }
+        let commodity = record
+            .conversion
+            .as_ref()
+            .map_or(&options.commodity, |conversion| &conversion.currency)
+            .to_uppercase();
+        writeln!(
+            writer,
+            "  {to}  {} {commodity}",
+            journal_amount(record.amount)
+        )?;
+        writeln!(
+            writer,
+            "  {from}  {} {commodity}",
+            journal_amount(-record.amount)
+        )?;
+    }
+
+    Ok(())
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -0,0 +1,15 @@
+//! Модуль предоставляет функционал для записи [CborRecords].
+
+use crate::records::CborRecords;
+
+use ciborium::ser::Error;
+
+use std::io::{self, Write};
+
+/// Записывает [CborRecords] в структуру, реализующую трейт [Write].
+pub fn print(writer: impl Write, records: &CborRecords) -> Result<(), io::Error> {
+    ciborium::into_writer(records.list(), writer).map_err(|err| match err {
+        Error::Io(err) => err,
+        Error::Value(err) => io::Error::other(err),
+    })
+}
@@ -1,16 +1,115 @@
 //! Модуль предоставляет функционал для записи [CsvRecords].
 
-use crate::CsvRecords;
+use crate::records::DETAILS;
+use crate::{CsvRecord, CsvRecords};
+
+use serde::Serialize;
 
 use std::io::{self, Write};
 
+/// Колонки с источником операции, см. [crate::records::Source].
+const SOURCE_COLUMNS: [&str; 2] = ["source_file", "source_row"];
+
+/// Колонки с применённым курсом, см. [crate::fx::Conversion].
+const CONVERSION_COLUMNS: [&str; 5] = [
+    "original_amount",
+    "original_currency",
+    "currency",
+    "rate",
+    "rate_date",
+];
+
+/// Значение колонки: сумма остаётся числом, чтобы записываться так же, как без выбора колонок.
+#[derive(Clone, Copy, Serialize)]
+#[serde(untagged)]
+enum Cell<'a> {
+    Number(f64),
+    Row(usize),
+    Text(&'a str),
+}
+
 /// Записывает [CsvRecords] в структуру, реализующую трейт [Write].
+/// Записываются только выбранные колонки [CsvRecords::columns] и те необязательные поля,
+/// которые заполнены хотя бы у одной операции. Если у операций известен источник, в конце добавляются колонки `source_file` и `source_row`,
+/// если суммы пересчитаны в другую валюту — колонки с применённым курсом,
+/// если операции разложены по категориям — колонка `category`.
 pub fn print(writer: impl Write, records: &CsvRecords) -> Result<(), io::Error> {
     let mut csv_writer = csv::Writer::from_writer(writer);
+    let details: Vec<&str> = DETAILS
+        .into_iter()
+        .filter(|column| records.list().iter().any(|r| r.detail(column).is_some()))
+        .collect();
+    let with_source = records.list().iter().any(|r| r.source.is_some());
+    let with_conversion = records.list().iter().any(|r| r.conversion.is_some());
+    let with_category = records.list().iter().any(|r| r.category.is_some());
+
+    if records.columns().is_all()
+        && details.is_empty()
+        && !with_source
+        && !with_conversion
+        && !with_category
+    {
+        for record in records.list() {
+            csv_writer.serialize(record)?;
+        }
+        return Ok(());
+    }
 
+    let columns = records.columns().fields();
+    let mut header = columns.to_vec();
+    header.extend(&details);
+    if with_source {
+        header.extend(SOURCE_COLUMNS);
+    }
+    if with_conversion {
+        header.extend(CONVERSION_COLUMNS);
+    }
+    if with_category {
+        header.push("category");
+    }
+    csv_writer.write_record(header)?;
     for record in records.list() {
-        csv_writer.serialize(record)?;
+        let mut row: Vec<Cell> = columns.iter().map(|field| cell(record, field)).collect();
+        row.extend(
+            details
+                .iter()
+                .map(|column| Cell::Text(record.detail(column).unwrap_or_default())),
+        );
+        if with_source {
+            match &record.source {
+                Some(source) => row.extend([Cell::Text(&source.file), Cell::Row(source.row)]),
+                None => row.extend([Cell::Text(""), Cell::Text("")]),
+            }
+        }
+        if with_conversion {
+            match &record.conversion {
+                Some(conversion) => row.extend([
+                    Cell::Number(conversion.original_amount),
+                    Cell::Text(&conversion.original_currency),
+                    Cell::Text(&conversion.currency),
+                    Cell::Number(conversion.rate),
+                    Cell::Text(&conversion.rate_date),
+                ]),
+                None => row.extend([Cell::Text(""); CONVERSION_COLUMNS.len()]),
+            }
+        }
+        if with_category {
+            row.push(Cell::Text(record.category.as_deref().unwrap_or_default()));
+        }
+        csv_writer.serialize(row)?;
     }
 
     Ok(())
 }
+
+fn cell<'a>(record: &'a CsvRecord, field: &str) -> Cell<'a> {
+    match field {
+        "from_client" => Cell::Text(&record.from_client),
+        "from_bank" => Cell::Text(&record.from_bank),
+        "to_client" => Cell::Text(&record.to_client),
+        "to_bank" => Cell::Text(&record.to_bank),
+        "transaction" => Cell::Text(&record.transaction),
+        "amount" => Cell::Number(record.amount),
+        _ => Cell::Text(&record.date),
+    }
+}
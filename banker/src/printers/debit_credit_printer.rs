@@ -0,0 +1,71 @@
+//! Модуль предоставляет функционал для записи [DebitCreditRecords].
+
+use crate::direction::Direction;
+use crate::records::{DETAILS, DebitCreditRecords};
+
+use std::io::{self, Write};
+
+/// Колонки, которые записываются всегда.
+const COLUMNS: [&str; 8] = [
+    "from_client",
+    "from_bank",
+    "to_client",
+    "to_bank",
+    "transaction",
+    "debit",
+    "credit",
+    "date",
+];
+
+/// Записывает [DebitCreditRecords] в структуру, реализующую трейт [Write].
+/// Необязательные поля записываются, только если заполнены хотя бы у одной операции.
+/// Ошибка, если наша сторона не участвует в какой-то операции.
+pub fn print(writer: impl Write, records: &DebitCreditRecords) -> Result<(), io::Error> {
+    let mut csv_writer = csv::Writer::from_writer(writer);
+    let details: Vec<&str> = DETAILS
+        .into_iter()
+        .filter(|detail| records.list().iter().any(|r| r.detail(detail).is_some()))
+        .collect();
+
+    if !records.list().is_empty() {
+        let mut header = COLUMNS.to_vec();
+        header.extend(&details);
+        csv_writer.write_record(header)?;
+    }
+
+    for record in records.list() {
+        let direction = records.party().direction(record).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "операция `{}` не является ни списанием, ни поступлением для `{}`",
+                    record.transaction,
+                    records.party().client
+                ),
+            )
+        })?;
+        let (debit, credit) = match direction {
+            Direction::Debit => (Some(record.amount), None),
+            Direction::Credit => (None, Some(record.amount)),
+        };
+
+        let details: Vec<&str> = details
+            .iter()
+            .map(|detail| record.detail(detail).unwrap_or_default())
+            .collect();
+
+        csv_writer.serialize((
+            &record.from_client,
+            &record.from_bank,
+            &record.to_client,
+            &record.to_bank,
+            &record.transaction,
+            debit,
+            credit,
+            &record.date,
+            details,
+        ))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+//! Модуль предоставляет функционал для записи различий [Diff].
+
+use crate::diff::Diff;
+use crate::records::{CsvRecord, DETAILS, FIELDS};
+
+use std::io::{self, Write};
+
+/// Записывает [Diff] текстом, по строке на операцию.
+pub fn print_text(mut writer: impl Write, diff: &Diff) -> Result<(), io::Error> {
+    for record in &diff.removed {
+        writeln!(writer, "- {}", describe(diff, record))?;
+    }
+    for record in &diff.added {
+        writeln!(writer, "+ {}", describe(diff, record))?;
+    }
+    for modified in &diff.modified {
+        let changes: Vec<String> = modified
+            .changes
+            .iter()
+            .map(|c| format!("{}: {} -> {}", c.field, c.old, c.new))
+            .collect();
+        writeln!(
+            writer,
+            "~ {}: {}",
+            key(&diff.key, &modified.key),
+            changes.join(", ")
+        )?;
+    }
+
+    writeln!(
+        writer,
+        "Добавлено: {}, удалено: {}, изменено: {}, без изменений: {}",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.modified.len(),
+        diff.unchanged
+    )
+}
+
+/// Записывает [Diff] объектом `json`, операции записываются с теми же ключами, что и в `csv`.
+pub fn print_json(mut writer: impl Write, diff: &Diff) -> Result<(), io::Error> {
+    serde_json::to_writer_pretty(&mut writer, diff)?;
+    writeln!(writer)
+}
+
+fn describe(diff: &Diff, record: &CsvRecord) -> String {
+    let fields: Vec<String> = FIELDS
+        .iter()
+        .filter(|field| !diff.key.iter().any(|k| k == *field))
+        .map(|&field| format!("{field}={}", record.field(field).unwrap_or_default()))
+        .chain(DETAILS.iter().filter_map(|&detail| {
+            record
+                .detail(detail)
+                .map(|value| format!("{detail}={value}"))
+        }))
+        .collect();
+    let values: Vec<String> = diff
+        .key
+        .iter()
+        .map(|field| record.field(field).unwrap_or_default())
+        .collect();
+    format!("{}: {}", key(&diff.key, &values), fields.join(", "))
+}
+
+fn key(fields: &[String], values: &[String]) -> String {
+    let pairs: Vec<String> = fields
+        .iter()
+        .zip(values)
+        .map(|(field, value)| format!("{field}={value}"))
+        .collect();
+    pairs.join(", ")
+}
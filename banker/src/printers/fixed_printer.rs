@@ -0,0 +1,90 @@
+//! Модуль предоставляет функционал для записи [FixedRecords].
+
+use crate::CsvRecord;
+use crate::records::{Align, DETAILS, FixedColumn, FixedLayout, FixedRecords, parse_date};
+
+use std::io::{self, Write};
+
+/// Записывает [FixedRecords] в структуру, реализующую трейт [Write]. Промежутки
+/// между колонками заполняются пробелами. Ошибка, если у операции заполнено
+/// необязательное поле, для которого нет колонки: иначе оно бы потерялось.
+pub fn print(mut writer: impl Write, records: &FixedRecords) -> Result<(), io::Error> {
+    let layout = records.layout();
+    layout
+        .validate()
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+
+    for record in records.list() {
+        let line = format_line(record, layout)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        writeln!(writer, "{line}")?;
+    }
+
+    Ok(())
+}
+
+fn format_line(record: &CsvRecord, layout: &FixedLayout) -> Result<String, String> {
+    if let Some(detail) = DETAILS.into_iter().find(|&detail| {
+        record.detail(detail).is_some() && !layout.columns.iter().any(|c| c.field == detail)
+    }) {
+        return Err(format!(
+            "у операции `{}` заполнено поле `{detail}`, но для него нет колонки",
+            record.transaction
+        ));
+    }
+
+    let mut line = vec![' '; layout.line_width()];
+
+    for column in &layout.columns {
+        let value = format_value(record, column)?;
+        let value = pad(&value, column).ok_or_else(|| {
+            format!(
+                "значение `{value}` поля `{}` не помещается в {} символов",
+                column.field, column.width
+            )
+        })?;
+
+        let start = column.start - 1;
+        for (i, c) in value.chars().enumerate() {
+            line[start + i] = c;
+        }
+    }
+
+    Ok(line.into_iter().collect())
+}
+
+fn format_value(record: &CsvRecord, column: &FixedColumn) -> Result<String, String> {
+    match (column.field.as_str(), column.decimals, &column.date_format) {
+        ("amount", Some(decimals), _) => {
+            let units = (record.amount * 10f64.powi(decimals as i32)).round() as i64;
+            Ok(units.to_string())
+        }
+        ("date", _, Some(format)) => {
+            let date = parse_date(&record.date)
+                .map_err(|err| format!("некорректная дата `{}`: {err}", record.date))?;
+            Ok(date.format(format).to_string())
+        }
+        (field, _, _) => Ok(record
+            .field(field)
+            .or_else(|| record.detail(field).map(String::from))
+            .unwrap_or_default()),
+    }
+}
+
+/// Дополняет значение до ширины колонки. Знак отрицательного числа при выравнивании
+/// вправо нулями остаётся перед нулями.
+fn pad(value: &str, column: &FixedColumn) -> Option<String> {
+    let length = value.chars().count();
+    if length > column.width {
+        return None;
+    }
+    let padding: String = std::iter::repeat_n(column.pad, column.width - length).collect();
+
+    Some(match column.align {
+        Align::Left => format!("{value}{padding}"),
+        Align::Right => match value.strip_prefix('-') {
+            Some(digits) if column.pad == '0' => format!("-{padding}{digits}"),
+            _ => format!("{padding}{value}"),
+        },
+    })
+}
@@ -0,0 +1,124 @@
+//! Модуль предоставляет функционал для записи [HtmlRecords] HTML отчётом.
+
+use crate::records::{FIELDS, HtmlRecords, format_amount, report_cells, to_cents};
+
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+const STYLE: &str = "body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; margin-bottom: 2em; }
+th, td { border: 1px solid #ccc; padding: 4px 8px; }
+th { background: #f0f0f0; text-align: left; }
+td.amount, th.amount { text-align: right; font-variant-numeric: tabular-nums; }
+tfoot td { font-weight: bold; }";
+
+/// Итоги по одному банку. Суммы считаются в копейках, чтобы не копить ошибку `f64`.
+#[derive(Default)]
+struct BankTotals {
+    sent_count: usize,
+    sent: i64,
+    received_count: usize,
+    received: i64,
+}
+
+/// Записывает [HtmlRecords] в структуру, реализующую трейт [Write].
+///
+/// Отчёт не ссылается на внешние ресурсы: стили встроены в страницу.
+pub fn print(mut writer: impl Write, records: &HtmlRecords) -> Result<(), io::Error> {
+    let mut totals: BTreeMap<&str, BankTotals> = BTreeMap::new();
+    for record in records.list() {
+        let cents = to_cents(record.amount);
+
+        let from = totals.entry(&record.from_bank).or_default();
+        from.sent_count += 1;
+        from.sent += cents;
+
+        let to = totals.entry(&record.to_bank).or_default();
+        to.received_count += 1;
+        to.received += cents;
+    }
+
+    writeln!(writer, "<!DOCTYPE html>")?;
+    writeln!(writer, "<html lang=\"ru\">")?;
+    writeln!(writer, "<head>")?;
+    writeln!(writer, "<meta charset=\"utf-8\">")?;
+    writeln!(writer, "<title>Банковские операции</title>")?;
+    writeln!(writer, "<style>\n{STYLE}\n</style>")?;
+    writeln!(writer, "</head>")?;
+    writeln!(writer, "<body>")?;
+
+    writeln!(writer, "<h1>Банковские операции</h1>")?;
+    writeln!(writer, "<table>")?;
+    let header: Vec<String> = FIELDS
+        .iter()
+        .map(|field| cell("th", field, field))
+        .collect();
+    writeln!(writer, "<thead><tr>{}</tr></thead>", header.concat())?;
+    writeln!(writer, "<tbody>")?;
+    for record in records.list() {
+        let row: Vec<String> = report_cells(record)
+            .iter()
+            .zip(FIELDS)
+            .map(|(value, field)| cell("td", field, value))
+            .collect();
+        writeln!(writer, "<tr>{}</tr>", row.concat())?;
+    }
+    writeln!(writer, "</tbody>")?;
+    writeln!(writer, "</table>")?;
+
+    writeln!(writer, "<h2>Итоги по банкам</h2>")?;
+    writeln!(writer, "<table>")?;
+    writeln!(
+        writer,
+        "<thead><tr><th>bank</th><th class=\"amount\">sent_count</th>\
+        <th class=\"amount\">sent</th><th class=\"amount\">received_count</th>\
+        <th class=\"amount\">received</th></tr></thead>"
+    )?;
+    writeln!(writer, "<tbody>")?;
+    for (bank, total) in &totals {
+        writeln!(
+            writer,
+            "<tr><td>{}</td><td class=\"amount\">{}</td><td class=\"amount\">{}</td>\
+            <td class=\"amount\">{}</td><td class=\"amount\">{}</td></tr>",
+            escape(bank),
+            total.sent_count,
+            from_cents(total.sent),
+            total.received_count,
+            from_cents(total.received)
+        )?;
+    }
+    writeln!(writer, "</tbody>")?;
+    let sum: i64 = records.list().iter().map(|r| to_cents(r.amount)).sum();
+    writeln!(
+        writer,
+        "<tfoot><tr><td>total</td><td class=\"amount\">{}</td>\
+        <td class=\"amount\">{}</td><td></td><td></td></tr></tfoot>",
+        records.list().len(),
+        from_cents(sum)
+    )?;
+    writeln!(writer, "</table>")?;
+
+    writeln!(writer, "</body>")?;
+    writeln!(writer, "</html>")
+}
+
+fn cell(tag: &str, field: &str, value: &str) -> String {
+    let class = if field == "amount" {
+        " class=\"amount\""
+    } else {
+        ""
+    };
+    format!("<{tag}{class}>{}</{tag}>", escape(value))
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn from_cents(cents: i64) -> String {
+    format_amount(cents as f64 / 100.0)
+}
@@ -0,0 +1,65 @@
+//! Модуль предоставляет функционал для записи [LedgerRecords] в журнал Ledger/hledger.
+
+use crate::records::{LedgerRecords, Syntax, journal_amount, journal_date, rate_date};
+
+use std::io::{self, Write};
+
+/// Записывает [LedgerRecords] в структуру, реализующую трейт [Write]. Валюта суммы
+/// берётся из пересчёта операции, если он был, иначе из [crate::records::JournalOptions].
+/// Исходная сумма и применённый курс записываются тегами транзакции.
+pub fn print(mut writer: impl Write, records: &LedgerRecords) -> Result<(), io::Error> {
+    let options = records.options();
+
+    for record in records.list() {
+        let date = journal_date(record)?;
+        let commodity = commodity(
+            record
+                .conversion
+                .as_ref()
+                .map_or(&options.commodity, |conversion| &conversion.currency),
+        );
+        let (from, to) = options.accounts(record, Syntax::Ledger);
+
+        writeln!(
+            writer,
+            "{date} ({}) {} -> {}",
+            record.transaction, record.from_client, record.to_client
+        )?;
+        if let Some(conversion) = &record.conversion {
+            writeln!(
+                writer,
+                "    ; original_amount: {} {}",
+                journal_amount(conversion.original_amount),
+                self::commodity(&conversion.original_currency)
+            )?;
+            writeln!(writer, "    ; rate: {}", conversion.rate)?;
+            writeln!(
+                writer,
+                "    ; rate_date: {}",
+                rate_date(&conversion.rate_date)
+            )?;
+        }
+        writeln!(
+            writer,
+            "    {to}  {} {commodity}",
+            journal_amount(record.amount)
+        )?;
+        writeln!(
+            writer,
+            "    {from}  {} {commodity}",
+            journal_amount(-record.amount)
+        )?;
+        writeln!(writer)?;
+    }
+
+    Ok(())
+}
+
+/// Валюта из одних букв пишется как есть, остальные берутся в кавычки.
+fn commodity(commodity: &str) -> String {
+    if commodity.chars().all(char::is_alphabetic) {
+        commodity.into()
+    } else {
+        format!("\"{}\"", commodity.replace('"', ""))
+    }
+}
@@ -0,0 +1,63 @@
+//! Модуль предоставляет функционал для записи [MarkdownRecords] таблицей Markdown.
+
+use crate::records::{FIELDS, MarkdownRecords, report_cells};
+
+use std::io::{self, Write};
+
+/// Записывает [MarkdownRecords] в структуру, реализующую трейт [Write].
+///
+/// Колонки дополняются пробелами, чтобы таблица читалась и без рендеринга.
+pub fn print(mut writer: impl Write, records: &MarkdownRecords) -> Result<(), io::Error> {
+    let rows: Vec<Vec<String>> = records
+        .list()
+        .iter()
+        .map(|record| report_cells(record).iter().map(|c| escape(c)).collect())
+        .collect();
+
+    let mut widths: Vec<usize> = FIELDS.iter().map(|field| field.len().max(3)).collect();
+    for row in &rows {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = widths[i].max(cell.chars().count());
+        }
+    }
+
+    let header: Vec<String> = FIELDS.iter().map(|field| field.to_string()).collect();
+    write_row(&mut writer, &header, &widths)?;
+
+    let rule: Vec<String> = FIELDS
+        .iter()
+        .zip(&widths)
+        .map(|(&field, &width)| match field {
+            "amount" => format!("{}:", "-".repeat(width - 1)),
+            _ => "-".repeat(width),
+        })
+        .collect();
+    write_row(&mut writer, &rule, &widths)?;
+
+    for row in &rows {
+        write_row(&mut writer, row, &widths)?;
+    }
+
+    Ok(())
+}
+
+fn write_row(writer: &mut impl Write, row: &[String], widths: &[usize]) -> Result<(), io::Error> {
+    let cells: Vec<String> = row
+        .iter()
+        .zip(widths)
+        .enumerate()
+        .map(|(i, (cell, &width))| {
+            if FIELDS[i] == "amount" {
+                format!("{cell:>width$}")
+            } else {
+                format!("{cell:<width$}")
+            }
+        })
+        .collect();
+
+    writeln!(writer, "| {} |", cells.join(" | "))
+}
+
+fn escape(cell: &str) -> String {
+    cell.replace('|', "\\|").replace('\n', " ")
+}
@@ -0,0 +1,11 @@
+//! Модуль предоставляет функционал для записи [MsgpackRecords].
+
+use crate::records::MsgpackRecords;
+
+use std::io::{self, Write};
+
+/// Записывает [MsgpackRecords] в структуру, реализующую трейт [Write].
+/// Операции пишутся картами с названиями полей, а не массивами.
+pub fn print(mut writer: impl Write, records: &MsgpackRecords) -> Result<(), io::Error> {
+    rmp_serde::encode::write_named(&mut writer, records.list()).map_err(io::Error::other)
+}
@@ -0,0 +1,40 @@
+//! Модуль предоставляет функционал для записи [ParquetRecords].
+
+use crate::columnar::{self, Compression};
+use crate::records::ParquetRecords;
+
+use parquet::arrow::ArrowWriter;
+use parquet::basic::{self, GzipLevel, ZstdLevel};
+use parquet::file::properties::WriterProperties;
+
+use std::io::{self, Write};
+
+/// Записывает [ParquetRecords] в структуру, реализующую трейт [Write].
+pub fn print(mut writer: impl Write, records: &ParquetRecords) -> Result<(), io::Error> {
+    let options = records.options();
+
+    let properties = WriterProperties::builder()
+        .set_max_row_group_size(options.row_group_size)
+        .set_compression(compression(options.compression))
+        .build();
+
+    let batch = columnar::to_batch(records.list()).map_err(io::Error::other)?;
+
+    // `ArrowWriter` требует `Send`, поэтому файл собирается в буфере.
+    let mut buffer = Vec::new();
+    let mut parquet_writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(properties))?;
+    parquet_writer.write(&batch)?;
+    parquet_writer.close()?;
+
+    writer.write_all(&buffer)
+}
+
+fn compression(compression: Compression) -> basic::Compression {
+    match compression {
+        Compression::None => basic::Compression::UNCOMPRESSED,
+        Compression::Snappy => basic::Compression::SNAPPY,
+        Compression::Gzip => basic::Compression::GZIP(GzipLevel::default()),
+        Compression::Lz4 => basic::Compression::LZ4_RAW,
+        Compression::Zstd => basic::Compression::ZSTD(ZstdLevel::default()),
+    }
+}
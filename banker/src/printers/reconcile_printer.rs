@@ -0,0 +1,136 @@
+//! Модуль предоставляет функционал для записи результата сверки [Reconciliation].
+
+use crate::reconcile::{MatchRule, Reconciliation};
+use crate::records::CsvRecord;
+
+use serde::Serialize;
+
+use std::io::{self, Write};
+
+/// Записывает [Reconciliation] текстом. Пары по номеру транзакции только считаются,
+/// остальные совпадения и несовпадения перечисляются по операциям.
+pub fn print_text(
+    mut writer: impl Write,
+    result: &Reconciliation,
+    statement: &[CsvRecord],
+    ledger: &[CsvRecord],
+) -> Result<(), io::Error> {
+    let by_transaction = result
+        .pairs
+        .iter()
+        .filter(|pair| pair.rule == MatchRule::Transaction)
+        .count();
+    writeln!(writer, "Совпали по номеру транзакции: {by_transaction}")?;
+
+    let tolerance: Vec<_> = result
+        .pairs
+        .iter()
+        .filter(|pair| pair.rule == MatchRule::Tolerance)
+        .collect();
+    writeln!(writer, "Совпали с допусками: {}", tolerance.len())?;
+    for pair in tolerance {
+        writeln!(
+            writer,
+            "  {} = {}",
+            describe(&statement[pair.statement]),
+            describe(&ledger[pair.ledger])
+        )?;
+    }
+
+    writeln!(
+        writer,
+        "Разнесены на несколько операций: {}",
+        result.splits.len()
+    )?;
+    for split in &result.splits {
+        let statement: Vec<String> = split
+            .statement
+            .iter()
+            .map(|&s| describe(&statement[s]))
+            .collect();
+        let ledger: Vec<String> = split.ledger.iter().map(|&l| describe(&ledger[l])).collect();
+        writeln!(
+            writer,
+            "  {} = {}",
+            statement.join(" + "),
+            ledger.join(" + ")
+        )?;
+    }
+
+    writeln!(writer, "Нет в учёте: {}", result.unmatched_statement.len())?;
+    for &s in &result.unmatched_statement {
+        writeln!(writer, "  {}", describe(&statement[s]))?;
+    }
+
+    writeln!(writer, "Нет в выписке: {}", result.unmatched_ledger.len())?;
+    for &l in &result.unmatched_ledger {
+        writeln!(writer, "  {}", describe(&ledger[l]))?;
+    }
+
+    Ok(())
+}
+
+/// Записывает [Reconciliation] объектом `json`, операции записываются целиком.
+pub fn print_json(
+    mut writer: impl Write,
+    result: &Reconciliation,
+    statement: &[CsvRecord],
+    ledger: &[CsvRecord],
+) -> Result<(), io::Error> {
+    let report = Report {
+        pairs: result
+            .pairs
+            .iter()
+            .map(|pair| PairReport {
+                rule: pair.rule,
+                statement: &statement[pair.statement],
+                ledger: &ledger[pair.ledger],
+            })
+            .collect(),
+        splits: result
+            .splits
+            .iter()
+            .map(|split| SplitReport {
+                statement: pick(&split.statement, statement),
+                ledger: pick(&split.ledger, ledger),
+            })
+            .collect(),
+        unmatched_statement: pick(&result.unmatched_statement, statement),
+        unmatched_ledger: pick(&result.unmatched_ledger, ledger),
+    };
+    serde_json::to_writer_pretty(&mut writer, &report)?;
+    writeln!(writer)
+}
+
+/// [Reconciliation], в котором номера операций заменены самими операциями.
+#[derive(Serialize)]
+struct Report<'a> {
+    pairs: Vec<PairReport<'a>>,
+    splits: Vec<SplitReport<'a>>,
+    unmatched_statement: Vec<&'a CsvRecord>,
+    unmatched_ledger: Vec<&'a CsvRecord>,
+}
+
+#[derive(Serialize)]
+struct PairReport<'a> {
+    rule: MatchRule,
+    statement: &'a CsvRecord,
+    ledger: &'a CsvRecord,
+}
+
+#[derive(Serialize)]
+struct SplitReport<'a> {
+    statement: Vec<&'a CsvRecord>,
+    ledger: Vec<&'a CsvRecord>,
+}
+
+fn pick<'a>(indices: &[usize], records: &'a [CsvRecord]) -> Vec<&'a CsvRecord> {
+    indices.iter().map(|&i| &records[i]).collect()
+}
+
+fn describe(record: &CsvRecord) -> String {
+    format!(
+        "{} {} {} -> {} {:.2}",
+        record.transaction, record.date, record.from_client, record.to_client, record.amount
+    )
+}
@@ -0,0 +1,94 @@
+//! Модуль предоставляет функционал для сохранения [SqliteRecords] в базу `sqlite`.
+
+use crate::parsers::sqlite_parser;
+use crate::records::{DETAILS, SQLITE_TABLE, SqliteMode, SqliteRecords, parse_date};
+
+use rusqlite::{Connection, params};
+
+use std::io;
+use std::path::Path;
+
+/// Сохраняет [SqliteRecords] в таблицу [SQLITE_TABLE] базы по пути `path`
+/// одной транзакцией. Необязательные поля из [DETAILS] хранятся в колонках,
+/// допускающих `NULL`; в таблицу, созданную без них, колонки добавляются.
+pub fn print(path: &Path, records: &SqliteRecords) -> Result<(), io::Error> {
+    save(path, records).map_err(io::Error::other)
+}
+
+fn save(path: &Path, records: &SqliteRecords) -> Result<(), rusqlite::Error> {
+    let mut connection = Connection::open(path)?;
+    let transaction = connection.transaction()?;
+
+    if records.mode() == SqliteMode::Replace {
+        transaction.execute_batch(&format!("DROP TABLE IF EXISTS {SQLITE_TABLE};"))?;
+    }
+    transaction.execute_batch(&format!(
+        "CREATE TABLE IF NOT EXISTS {SQLITE_TABLE} (
+            id INTEGER PRIMARY KEY,
+            from_client TEXT NOT NULL,
+            from_bank TEXT NOT NULL,
+            to_client TEXT NOT NULL,
+            to_bank TEXT NOT NULL,
+            transaction_id TEXT NOT NULL,
+            amount REAL NOT NULL,
+            date TEXT NOT NULL,
+            description TEXT,
+            end_to_end_id TEXT,
+            remittance_reference TEXT,
+            value_date TEXT
+        ) STRICT;
+        CREATE INDEX IF NOT EXISTS {SQLITE_TABLE}_date ON {SQLITE_TABLE} (date);
+        CREATE INDEX IF NOT EXISTS {SQLITE_TABLE}_from_client ON {SQLITE_TABLE} (from_client);
+        CREATE INDEX IF NOT EXISTS {SQLITE_TABLE}_to_client ON {SQLITE_TABLE} (to_client);
+        CREATE INDEX IF NOT EXISTS {SQLITE_TABLE}_from_bank ON {SQLITE_TABLE} (from_bank);
+        CREATE INDEX IF NOT EXISTS {SQLITE_TABLE}_to_bank ON {SQLITE_TABLE} (to_bank);"
+    ))?;
+
+    let existing = sqlite_parser::columns(&transaction)?;
+    for detail in DETAILS.iter().filter(|detail| !existing.contains(**detail)) {
+        transaction.execute_batch(&format!(
+            "ALTER TABLE {SQLITE_TABLE} ADD COLUMN {detail} TEXT;"
+        ))?;
+    }
+
+    {
+        let mut insert = transaction.prepare(&format!(
+            "INSERT INTO {SQLITE_TABLE} \
+            (from_client, from_bank, to_client, to_bank, transaction_id, amount, date, \
+            description, end_to_end_id, remittance_reference, value_date) \
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)"
+        ))?;
+
+        for record in records.list() {
+            // Даты храним в ISO формате, чтобы их можно было сравнивать в запросах.
+            let date = iso_date(&record.date)?;
+            let value_date = record.value_date.as_deref().map(iso_date).transpose()?;
+
+            insert.execute(params![
+                record.from_client,
+                record.from_bank,
+                record.to_client,
+                record.to_bank,
+                record.transaction,
+                record.amount,
+                date,
+                record.description,
+                record.end_to_end_id,
+                record.remittance_reference,
+                value_date,
+            ])?;
+        }
+    }
+
+    transaction.commit()
+}
+
+fn iso_date(date: &str) -> Result<String, rusqlite::Error> {
+    parse_date(date)
+        .map(|date| date.format("%Y-%m-%d").to_string())
+        .map_err(|err| {
+            rusqlite::Error::ToSqlConversionFailure(
+                format!("некорректная дата `{date}`: {err}").into(),
+            )
+        })
+}
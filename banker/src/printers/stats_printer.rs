@@ -0,0 +1,87 @@
+//! Модуль предоставляет функционал для записи итогов [Summary].
+
+use crate::stats::{Group, Summary};
+
+use std::io::{self, Write};
+
+const SEPARATOR: &str = "  ";
+const TOTALS: [&str; 5] = ["count", "sum", "min", "max", "avg"];
+
+/// Записывает [Summary] текстовой таблицей, числа прижимаются вправо.
+pub fn print_table(mut writer: impl Write, summary: &Summary) -> Result<(), io::Error> {
+    let header = header(summary);
+    let rows: Vec<Vec<String>> = summary.groups.iter().map(cells).collect();
+    let numeric: Vec<bool> = header
+        .iter()
+        .enumerate()
+        .map(|(i, field)| i >= summary.group_by.len() || field == "amount")
+        .collect();
+
+    let mut widths: Vec<usize> = header.iter().map(|cell| cell.chars().count()).collect();
+    for row in &rows {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = widths[i].max(cell.chars().count());
+        }
+    }
+
+    let rule: Vec<String> = widths.iter().map(|&w| "-".repeat(w)).collect();
+    for row in [&header, &rule].into_iter().chain(&rows) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .zip(&numeric)
+            .map(|((cell, &width), &numeric)| {
+                if numeric {
+                    format!("{cell:>width$}")
+                } else {
+                    format!("{cell:<width$}")
+                }
+            })
+            .collect();
+        writeln!(writer, "{}", cells.join(SEPARATOR).trim_end())?;
+    }
+
+    Ok(())
+}
+
+/// Записывает [Summary] в `csv` с заголовком.
+pub fn print_csv(writer: impl Write, summary: &Summary) -> Result<(), io::Error> {
+    let mut csv_writer = csv::Writer::from_writer(writer);
+
+    csv_writer.write_record(header(summary))?;
+    for group in &summary.groups {
+        csv_writer.write_record(cells(group))?;
+    }
+
+    Ok(())
+}
+
+/// Записывает [Summary] объектом `json`, суммы записываются числами.
+pub fn print_json(mut writer: impl Write, summary: &Summary) -> Result<(), io::Error> {
+    serde_json::to_writer_pretty(&mut writer, summary)?;
+    writeln!(writer)
+}
+
+fn header(summary: &Summary) -> Vec<String> {
+    summary
+        .group_by
+        .iter()
+        .cloned()
+        .chain(TOTALS.iter().map(|name| name.to_string()))
+        .collect()
+}
+
+fn cells(group: &Group) -> Vec<String> {
+    group
+        .key
+        .iter()
+        .cloned()
+        .chain([
+            group.count.to_string(),
+            group.sum.to_string(),
+            group.min.to_string(),
+            group.max.to_string(),
+            group.avg.to_string(),
+        ])
+        .collect()
+}
@@ -0,0 +1,106 @@
+//! Модуль предоставляет функционал для записи [TableRecords] текстовой таблицей.
+
+use crate::records::{TableRecords, report_cells};
+
+use std::io::{self, Write};
+
+const SEPARATOR: &str = "  ";
+const MIN_WIDTH: usize = 3;
+
+/// Записывает [TableRecords] в структуру, реализующую трейт [Write].
+///
+/// Колонки выравниваются по ширине, суммы прижимаются вправо. Если задана
+/// максимальная ширина, самые широкие текстовые колонки обрезаются с многоточием.
+/// Записываются только колонки из [crate::records::TableOptions::columns].
+pub fn print(mut writer: impl Write, records: &TableRecords) -> Result<(), io::Error> {
+    let fields = records.options().columns.fields();
+    let indices = records.options().columns.indices();
+    let rows: Vec<Vec<String>> = records
+        .list()
+        .iter()
+        .map(|record| {
+            let mut cells = report_cells(record);
+            indices
+                .iter()
+                .map(|&i| std::mem::take(&mut cells[i]))
+                .collect()
+        })
+        .collect();
+
+    let mut widths: Vec<usize> = fields.iter().map(|field| width(field)).collect();
+    for row in &rows {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = widths[i].max(width(cell));
+        }
+    }
+
+    if let Some(max_width) = records.options().max_width {
+        shrink(&mut widths, fields, max_width);
+    }
+
+    let header: Vec<String> = fields.iter().map(|field| field.to_string()).collect();
+    let rule: Vec<String> = widths.iter().map(|&w| "-".repeat(w)).collect();
+
+    write_row(&mut writer, &header, fields, &widths)?;
+    write_row(&mut writer, &rule, fields, &widths)?;
+    for row in &rows {
+        write_row(&mut writer, row, fields, &widths)?;
+    }
+
+    Ok(())
+}
+
+/// Уменьшает самые широкие колонки, пока таблица не поместится.
+/// Сумма и дата не обрезаются.
+fn shrink(widths: &mut [usize], fields: &[&str], max_width: usize) {
+    let total =
+        |widths: &[usize]| widths.iter().sum::<usize>() + SEPARATOR.len() * (widths.len() - 1);
+
+    while total(widths) > max_width {
+        let widest = (0..widths.len())
+            .filter(|&i| !matches!(fields[i], "amount" | "date"))
+            .filter(|&i| widths[i] > MIN_WIDTH)
+            .max_by_key(|&i| widths[i]);
+
+        match widest {
+            Some(i) => widths[i] -= 1,
+            None => break,
+        }
+    }
+}
+
+fn write_row(
+    writer: &mut impl Write,
+    row: &[String],
+    fields: &[&str],
+    widths: &[usize],
+) -> Result<(), io::Error> {
+    let cells: Vec<String> = row
+        .iter()
+        .zip(widths)
+        .enumerate()
+        .map(|(i, (cell, &width))| {
+            let cell = truncate(cell, width);
+            if fields[i] == "amount" {
+                format!("{cell:>width$}")
+            } else {
+                format!("{cell:<width$}")
+            }
+        })
+        .collect();
+
+    writeln!(writer, "{}", cells.join(SEPARATOR).trim_end())
+}
+
+fn truncate(cell: &str, width: usize) -> String {
+    if self::width(cell) <= width {
+        return cell.to_string();
+    }
+    let mut truncated: String = cell.chars().take(width.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
+}
+
+fn width(cell: &str) -> usize {
+    cell.chars().count()
+}
@@ -0,0 +1,14 @@
+//! Модуль предоставляет функционал для записи [TomlRecords].
+
+use crate::records::{TomlDocument, TomlRecords};
+
+use std::io::{self, Write};
+
+/// Записывает [TomlRecords] в структуру, реализующую трейт [Write].
+pub fn print(mut writer: impl Write, records: &TomlRecords) -> Result<(), io::Error> {
+    let document = TomlDocument {
+        transaction: records.list(),
+    };
+    let document = toml::to_string(&document).map_err(io::Error::other)?;
+    writer.write_all(document.as_bytes())
+}
@@ -0,0 +1,105 @@
+//! Модуль предоставляет функционал для записи [XmlRecords] и `xsd` схемы к ним.
+
+use crate::records::{DETAILS, FIELDS, XmlOptions, XmlRecords};
+
+use quick_xml::Writer;
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+
+use std::io::{self, Write};
+
+/// Записывает [XmlRecords] в структуру, реализующую трейт [Write].
+/// Необязательные поля из [DETAILS] записываются, только если заполнены.
+pub fn print(writer: impl Write, records: &XmlRecords) -> Result<(), io::Error> {
+    let options = records.options();
+    validate(options)?;
+
+    let mut xml_writer = Writer::new_with_indent(writer, b' ', 2);
+    xml_writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    xml_writer.write_event(Event::Start(BytesStart::new(&options.root)))?;
+
+    for record in records.list() {
+        xml_writer.write_event(Event::Start(BytesStart::new(&options.record)))?;
+        for field in FIELDS {
+            let value = record.field(field).unwrap_or_default();
+            xml_writer
+                .create_element(options.element(field))
+                .write_text_content(BytesText::new(&value))?;
+        }
+        for detail in DETAILS {
+            if let Some(value) = record.detail(detail) {
+                xml_writer
+                    .create_element(options.element(detail))
+                    .write_text_content(BytesText::new(value))?;
+            }
+        }
+        xml_writer.write_event(Event::End(BytesEnd::new(&options.record)))?;
+    }
+
+    xml_writer.write_event(Event::End(BytesEnd::new(&options.root)))?;
+    writeln!(xml_writer.get_mut())
+}
+
+/// Записывает `xsd` схему для `xml` с названиями элементов из [XmlOptions].
+pub fn print_schema(mut writer: impl Write, options: &XmlOptions) -> Result<(), io::Error> {
+    validate(options)?;
+
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        writer,
+        r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema" elementFormDefault="qualified">"#
+    )?;
+    writeln!(writer, r#"  <xs:element name="{}">"#, options.root)?;
+    writeln!(writer, r#"    <xs:complexType>"#)?;
+    writeln!(writer, r#"      <xs:sequence>"#)?;
+    writeln!(
+        writer,
+        r#"        <xs:element name="{}" minOccurs="0" maxOccurs="unbounded">"#,
+        options.record
+    )?;
+    writeln!(writer, r#"          <xs:complexType>"#)?;
+    writeln!(writer, r#"            <xs:sequence>"#)?;
+    for field in FIELDS {
+        let kind = match field {
+            "amount" => "xs:decimal",
+            "date" => "dateType",
+            _ => "xs:string",
+        };
+        writeln!(
+            writer,
+            r#"              <xs:element name="{}" type="{kind}"/>"#,
+            options.element(field)
+        )?;
+    }
+    for detail in DETAILS {
+        let kind = match detail {
+            "value_date" => "dateType",
+            _ => "xs:string",
+        };
+        writeln!(
+            writer,
+            r#"              <xs:element name="{}" type="{kind}" minOccurs="0"/>"#,
+            options.element(detail)
+        )?;
+    }
+    writeln!(writer, r#"            </xs:sequence>"#)?;
+    writeln!(writer, r#"          </xs:complexType>"#)?;
+    writeln!(writer, r#"        </xs:element>"#)?;
+    writeln!(writer, r#"      </xs:sequence>"#)?;
+    writeln!(writer, r#"    </xs:complexType>"#)?;
+    writeln!(writer, r#"  </xs:element>"#)?;
+    writeln!(writer, r#"  <xs:simpleType name="dateType">"#)?;
+    writeln!(writer, r#"    <xs:restriction base="xs:string">"#)?;
+    writeln!(
+        writer,
+        r#"      <xs:pattern value="\d{{2}}-\d{{2}}-\d{{4}}"/>"#
+    )?;
+    writeln!(writer, r#"    </xs:restriction>"#)?;
+    writeln!(writer, r#"  </xs:simpleType>"#)?;
+    writeln!(writer, r#"</xs:schema>"#)
+}
+
+fn validate(options: &XmlOptions) -> Result<(), io::Error> {
+    options
+        .validate()
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))
+}
@@ -0,0 +1,10 @@
+//! Модуль предоставляет функционал для записи [YamlRecords].
+
+use crate::records::YamlRecords;
+
+use std::io::{self, Write};
+
+/// Записывает [YamlRecords] в структуру, реализующую трейт [Write].
+pub fn print(writer: impl Write, records: &YamlRecords) -> Result<(), io::Error> {
+    serde_yaml_ng::to_writer(writer, records.list()).map_err(io::Error::other)
+}
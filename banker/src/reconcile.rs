@@ -0,0 +1,384 @@
+//! Модуль сверяет операции банковской выписки с ожидаемыми платежами из нашего учёта.
+//!
+//! Сначала операции сопоставляются по номеру транзакции, затем оставшиеся по допускам:
+//! суммы отличаются не больше чем на [ReconcileOptions::amount_epsilon], даты не больше
+//! чем на [ReconcileOptions::date_days] дней, а имена отправителя и получателя похожи
+//! не меньше чем на [ReconcileOptions::name_similarity]. Напоследок ищутся операции,
+//! разнесённые на несколько: одна операция выписки на сумму нескольких операций учёта
+//! или наоборот.
+
+use crate::printers::reconcile_printer;
+use crate::records::{CsvRecord, parse_date, to_cents};
+
+use chrono::{Days, NaiveDate};
+use serde::Serialize;
+
+use std::collections::{HashMap, VecDeque};
+use std::io::{self, Write};
+
+/// Сколько операций другой стороны перебирается при поиске разнесённой операции.
+const MAX_SPLIT_CANDIDATES: usize = 12;
+
+/// Допуски сверки.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReconcileOptions {
+    /// Допустимая разница сумм.
+    pub amount_epsilon: f64,
+    /// Допустимая разница дат в днях.
+    pub date_days: u64,
+    /// Наименьшая похожесть имён клиентов от 0 до 1, 1 — имена совпадают
+    /// без учёта регистра, знаков препинания и порядка слов.
+    pub name_similarity: f64,
+}
+
+impl Default for ReconcileOptions {
+    fn default() -> Self {
+        Self {
+            amount_epsilon: 0.0,
+            date_days: 0,
+            name_similarity: 0.8,
+        }
+    }
+}
+
+impl ReconcileOptions {
+    /// Проверяет, что допуски не отрицательные, а похожесть не больше 1.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.amount_epsilon.is_nan() || self.amount_epsilon < 0.0 {
+            return Err("допуск суммы не может быть отрицательным".into());
+        }
+        if !(0.0..=1.0).contains(&self.name_similarity) {
+            return Err("похожесть имён задаётся числом от 0 до 1".into());
+        }
+        Ok(())
+    }
+}
+
+/// Правило, по которому сопоставлены операции.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchRule {
+    /// Совпал номер транзакции.
+    Transaction,
+    /// Совпали сумма, дата и клиенты с учётом допусков.
+    Tolerance,
+}
+
+/// Пара сопоставленных операций: номера в выписке и в учёте, начиная с 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Pair {
+    pub statement: usize,
+    pub ledger: usize,
+    pub rule: MatchRule,
+}
+
+/// Операция, разнесённая на несколько: с одной стороны одна операция,
+/// с другой несколько, сумма которых совпадает с её суммой.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Split {
+    pub statement: Vec<usize>,
+    pub ledger: Vec<usize>,
+}
+
+/// Результат сверки, операции указаны номерами в выписке и в учёте.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Reconciliation {
+    pub pairs: Vec<Pair>,
+    pub splits: Vec<Split>,
+    /// Операции выписки, которых нет в учёте.
+    pub unmatched_statement: Vec<usize>,
+    /// Операции учёта, которых нет в выписке.
+    pub unmatched_ledger: Vec<usize>,
+}
+
+/// Сверяет выписку с учётом.
+/// Ошибка, если допуски некорректны или у операции некорректная дата.
+pub fn reconcile(
+    statement: &[CsvRecord],
+    ledger: &[CsvRecord],
+    options: &ReconcileOptions,
+) -> Result<Reconciliation, String> {
+    options.validate()?;
+
+    let statement = Side::new(statement)?;
+    let ledger = Side::new(ledger)?;
+    let epsilon = to_cents(options.amount_epsilon);
+    let mut matched_statement = vec![false; statement.len()];
+    let mut matched_ledger = vec![false; ledger.len()];
+    let mut result = Reconciliation::default();
+
+    // Номер транзакции: повторяющиеся номера сопоставляются по порядку.
+    let mut by_transaction: HashMap<&str, VecDeque<usize>> = HashMap::new();
+    for (l, record) in ledger.records.iter().enumerate() {
+        if !record.transaction.is_empty() {
+            by_transaction
+                .entry(record.transaction.as_str())
+                .or_default()
+                .push_back(l);
+        }
+    }
+    for (s, record) in statement.records.iter().enumerate() {
+        let found = by_transaction
+            .get_mut(record.transaction.as_str())
+            .and_then(VecDeque::pop_front);
+        if let Some(l) = found {
+            matched_statement[s] = true;
+            matched_ledger[l] = true;
+            result.pairs.push(Pair {
+                statement: s,
+                ledger: l,
+                rule: MatchRule::Transaction,
+            });
+        }
+    }
+
+    // Допуски: из подходящих берётся ближайшая по сумме, затем по дате. Имена, самая
+    // дорогая проверка, сравниваются только у операций, подходящих по сумме и дате.
+    for s in unmatched(&matched_statement) {
+        let best = ledger
+            .within_amount(statement.cents[s], epsilon)
+            .filter(|&l| !matched_ledger[l])
+            .filter(|&l| close(&statement, s, &ledger, l, options))
+            .min_by_key(|&l| {
+                (
+                    (statement.cents[s] - ledger.cents[l]).abs(),
+                    days_between(statement.dates[s], ledger.dates[l]),
+                    l,
+                )
+            });
+        if let Some(l) = best {
+            matched_statement[s] = true;
+            matched_ledger[l] = true;
+            result.pairs.push(Pair {
+                statement: s,
+                ledger: l,
+                rule: MatchRule::Tolerance,
+            });
+        }
+    }
+
+    // Одна операция выписки на несколько операций учёта.
+    for s in unmatched(&matched_statement) {
+        let candidates: Vec<usize> = ledger
+            .within_days(statement.dates[s], options.date_days)
+            .into_iter()
+            .filter(|&l| !matched_ledger[l] && close(&statement, s, &ledger, l, options))
+            .take(MAX_SPLIT_CANDIDATES)
+            .collect();
+        if let Some(parts) = find_parts(statement.cents[s], &candidates, &ledger.cents, epsilon) {
+            matched_statement[s] = true;
+            parts.iter().for_each(|&l| matched_ledger[l] = true);
+            result.splits.push(Split {
+                statement: vec![s],
+                ledger: parts,
+            });
+        }
+    }
+
+    // Одна операция учёта на несколько операций выписки.
+    for l in unmatched(&matched_ledger) {
+        let candidates: Vec<usize> = statement
+            .within_days(ledger.dates[l], options.date_days)
+            .into_iter()
+            .filter(|&s| !matched_statement[s] && close(&statement, s, &ledger, l, options))
+            .take(MAX_SPLIT_CANDIDATES)
+            .collect();
+        if let Some(parts) = find_parts(ledger.cents[l], &candidates, &statement.cents, epsilon) {
+            matched_ledger[l] = true;
+            parts.iter().for_each(|&s| matched_statement[s] = true);
+            result.splits.push(Split {
+                statement: parts,
+                ledger: vec![l],
+            });
+        }
+    }
+
+    result.pairs.sort_by_key(|pair| pair.statement);
+    result.unmatched_statement = unmatched(&matched_statement);
+    result.unmatched_ledger = unmatched(&matched_ledger);
+
+    Ok(result)
+}
+
+impl Reconciliation {
+    /// Записывает результат сверки текстом: итоги по каждому виду совпадений
+    /// и операции, которые сопоставлены не по номеру или не сопоставлены вовсе.
+    pub fn print_text(
+        &self,
+        writer: impl Write,
+        statement: &[CsvRecord],
+        ledger: &[CsvRecord],
+    ) -> Result<(), io::Error> {
+        reconcile_printer::print_text(writer, self, statement, ledger)
+    }
+
+    /// Записывает результат сверки объектом `json` с ключами `pairs`, `splits`,
+    /// `unmatched_statement` и `unmatched_ledger`.
+    pub fn print_json(
+        &self,
+        writer: impl Write,
+        statement: &[CsvRecord],
+        ledger: &[CsvRecord],
+    ) -> Result<(), io::Error> {
+        reconcile_printer::print_json(writer, self, statement, ledger)
+    }
+}
+
+/// Операции одной стороны с заранее разобранными суммами, датами и именами
+/// и номерами операций, упорядоченными по сумме и по дате для поиска по диапазону.
+struct Side<'a> {
+    records: &'a [CsvRecord],
+    cents: Vec<i64>,
+    dates: Vec<NaiveDate>,
+    names: Vec<(String, String)>,
+    by_cents: Vec<usize>,
+    by_date: Vec<usize>,
+}
+
+impl<'a> Side<'a> {
+    fn new(records: &'a [CsvRecord]) -> Result<Self, String> {
+        let dates = records
+            .iter()
+            .map(|record| {
+                parse_date(&record.date).map_err(|err| {
+                    format!(
+                        "операция `{}`: некорректная дата: {err}",
+                        record.transaction
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let cents: Vec<i64> = records.iter().map(|r| to_cents(r.amount)).collect();
+        let mut by_cents: Vec<usize> = (0..records.len()).collect();
+        by_cents.sort_by_key(|&i| cents[i]);
+        let mut by_date: Vec<usize> = (0..records.len()).collect();
+        by_date.sort_by_key(|&i| dates[i]);
+        Ok(Self {
+            records,
+            cents,
+            dates,
+            by_cents,
+            by_date,
+            names: records
+                .iter()
+                .map(|r| (normalize(&r.from_client), normalize(&r.to_client)))
+                .collect(),
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Операции с суммой от `cents - epsilon` до `cents + epsilon`.
+    fn within_amount(&self, cents: i64, epsilon: i64) -> impl Iterator<Item = usize> + '_ {
+        let start = self
+            .by_cents
+            .partition_point(|&i| self.cents[i] < cents.saturating_sub(epsilon));
+        self.by_cents[start..]
+            .iter()
+            .copied()
+            .take_while(move |&i| self.cents[i] <= cents.saturating_add(epsilon))
+    }
+
+    /// Операции не дальше `days` дней от `date` в порядке их номеров.
+    fn within_days(&self, date: NaiveDate, days: u64) -> Vec<usize> {
+        let earliest = date
+            .checked_sub_days(Days::new(days))
+            .unwrap_or(NaiveDate::MIN);
+        let latest = date
+            .checked_add_days(Days::new(days))
+            .unwrap_or(NaiveDate::MAX);
+        let start = self.by_date.partition_point(|&i| self.dates[i] < earliest);
+        let mut found: Vec<usize> = self.by_date[start..]
+            .iter()
+            .copied()
+            .take_while(|&i| self.dates[i] <= latest)
+            .collect();
+        found.sort_unstable();
+        found
+    }
+}
+
+/// Даты и клиенты операций совпадают с учётом допусков, суммы не сравниваются.
+fn close(statement: &Side, s: usize, ledger: &Side, l: usize, options: &ReconcileOptions) -> bool {
+    let (statement_from, statement_to) = &statement.names[s];
+    let (ledger_from, ledger_to) = &ledger.names[l];
+    days_between(statement.dates[s], ledger.dates[l]) <= options.date_days
+        && similarity(statement_from, ledger_from) >= options.name_similarity
+        && similarity(statement_to, ledger_to) >= options.name_similarity
+}
+
+fn unmatched(matched: &[bool]) -> Vec<usize> {
+    (0..matched.len()).filter(|&i| !matched[i]).collect()
+}
+
+fn days_between(a: NaiveDate, b: NaiveDate) -> u64 {
+    (a - b).num_days().unsigned_abs()
+}
+
+/// Ищет не меньше двух операций из `candidates`, сумма которых равна `total` с допуском.
+fn find_parts(total: i64, candidates: &[usize], cents: &[i64], epsilon: i64) -> Option<Vec<usize>> {
+    fn search(
+        rest: &[usize],
+        cents: &[i64],
+        remaining: i64,
+        epsilon: i64,
+        chosen: &mut Vec<usize>,
+    ) -> bool {
+        if chosen.len() >= 2 && remaining.abs() <= epsilon {
+            return true;
+        }
+        for (i, &candidate) in rest.iter().enumerate() {
+            chosen.push(candidate);
+            if search(
+                &rest[i + 1..],
+                cents,
+                remaining - cents[candidate],
+                epsilon,
+                chosen,
+            ) {
+                return true;
+            }
+            chosen.pop();
+        }
+        false
+    }
+
+    let mut chosen = Vec::new();
+    search(candidates, cents, total, epsilon, &mut chosen).then_some(chosen)
+}
+
+/// Приводит имя к сравнимому виду: нижний регистр, только буквы и цифры,
+/// слова по алфавиту.
+fn normalize(name: &str) -> String {
+    let lower = name.to_lowercase();
+    let mut words: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+    words.sort_unstable();
+    words.join(" ")
+}
+
+/// Похожесть строк от 0 до 1 по расстоянию Левенштейна.
+fn similarity(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    1.0 - previous[b.len()] as f64 / longest as f64
+}
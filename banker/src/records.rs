@@ -1,14 +1,149 @@
 //! Модуль содержащий структуры, представляющие данные в разных форматах.
 
+#[cfg(feature = "columnar")]
+mod arrow_records;
+#[cfg(feature = "cbor")]
+mod cbor_records;
 mod csv_records;
+mod debit_credit_records;
+mod fixed_records;
+mod journal_records;
 mod json_records;
+#[cfg(feature = "msgpack")]
+mod msgpack_records;
+#[cfg(feature = "columnar")]
+mod parquet_records;
+mod report_records;
+#[cfg(feature = "sqlite")]
+mod sqlite_records;
+mod toml_records;
+mod xml_records;
+mod yaml_records;
 
-pub use csv_records::{CsvRecord, CsvRecords};
+#[cfg(feature = "columnar")]
+pub use crate::columnar::Compression;
+#[cfg(feature = "columnar")]
+pub use arrow_records::{ArrowOptions, ArrowRecords};
+#[cfg(feature = "cbor")]
+pub use cbor_records::CborRecords;
+pub use csv_records::{CsvRecord, CsvRecords, Source};
+pub use debit_credit_records::DebitCreditRecords;
+pub(crate) use debit_credit_records::DebitCreditRow;
+pub use fixed_records::{Align, FixedColumn, FixedLayout, FixedRecords};
+pub use journal_records::{BeancountRecords, JournalOptions, LedgerRecords};
+pub(crate) use journal_records::{Syntax, journal_amount, journal_date, rate_date};
 pub use json_records::{JsonRecord, JsonRecords};
+#[cfg(feature = "msgpack")]
+pub use msgpack_records::MsgpackRecords;
+#[cfg(feature = "columnar")]
+pub use parquet_records::{ParquetOptions, ParquetRecords};
+pub use report_records::{HtmlRecords, MarkdownRecords, TableOptions, TableRecords};
+pub(crate) use report_records::{format_amount, report_cells};
+#[cfg(feature = "sqlite")]
+pub use sqlite_records::{SQLITE_TABLE, SqliteMode, SqliteRecords};
+pub(crate) use toml_records::TomlDocument;
+pub use toml_records::TomlRecords;
+pub use xml_records::{XmlOptions, XmlRecords};
+pub use yaml_records::YamlRecords;
 
 use crate::parsers::ParseError;
 
+use chrono::NaiveDate;
+
 use std::io::{self, Read, Write};
+use std::str::FromStr;
+
+/// Названия полей банковской операции в том порядке, в котором они идут в [CsvRecord].
+pub const FIELDS: [&str; 7] = [
+    "from_client",
+    "from_bank",
+    "to_client",
+    "to_bank",
+    "transaction",
+    "amount",
+    "date",
+];
+
+/// Названия необязательных полей операции в том порядке, в котором они идут в [CsvRecord].
+/// Форматы с колонками или элементами записывают их, только если они заполнены.
+pub const DETAILS: [&str; 4] = [
+    "description",
+    "end_to_end_id",
+    "remittance_reference",
+    "value_date",
+];
+
+/// Формат дат в банковских операциях, например `24-01-2025`.
+pub const DATE_FORMAT: &str = "%d-%m-%Y";
+
+/// Колонки для записи операций: поля из [FIELDS] в нужном порядке.
+/// По умолчанию записываются все поля.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Columns(Vec<&'static str>);
+
+impl Columns {
+    /// Собирает колонки из названий полей, каждое поле можно указать один раз.
+    pub fn new<S: AsRef<str>>(fields: impl IntoIterator<Item = S>) -> Result<Self, String> {
+        let mut columns = Vec::new();
+        for field in fields {
+            let field = field.as_ref();
+            let known = FIELDS
+                .iter()
+                .find(|&&known| known == field)
+                .ok_or_else(|| format!("неизвестное поле `{field}`"))?;
+            if columns.contains(known) {
+                return Err(format!("поле `{field}` указано дважды"));
+            }
+            columns.push(*known);
+        }
+        if columns.is_empty() {
+            return Err("нужна хотя бы одна колонка".into());
+        }
+        Ok(Self(columns))
+    }
+
+    pub fn fields(&self) -> &[&'static str] {
+        &self.0
+    }
+
+    /// Записываются ли все поля в исходном порядке.
+    pub fn is_all(&self) -> bool {
+        self.0 == FIELDS
+    }
+
+    /// Номера колонок в [FIELDS].
+    pub(crate) fn indices(&self) -> Vec<usize> {
+        self.0
+            .iter()
+            .filter_map(|field| FIELDS.iter().position(|known| known == field))
+            .collect()
+    }
+}
+
+impl Default for Columns {
+    fn default() -> Self {
+        Self(FIELDS.to_vec())
+    }
+}
+
+/// Колонки через запятую, например `date,amount,to_client`.
+impl FromStr for Columns {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Columns::new(s.split(',').map(str::trim))
+    }
+}
+
+/// Разбирает дату операции в формате [DATE_FORMAT].
+pub(crate) fn parse_date(date: &str) -> Result<NaiveDate, chrono::ParseError> {
+    NaiveDate::parse_from_str(date, DATE_FORMAT)
+}
+
+/// Переводит сумму в копейки, чтобы складывать суммы без накопления ошибки округления.
+pub(crate) fn to_cents(amount: f64) -> i64 {
+    (amount * 100.0).round() as i64
+}
 
 /// Трейт для парсинга данных из переданного источника в новую структуру.
 pub trait Parse<T>: Sized {
@@ -0,0 +1,89 @@
+//! Модуль содержит функционал, связанный со списком операций в формате `arrow ipc` [ArrowRecords].
+
+use super::{Compression, Parse, Print};
+use crate::parsers::{ParseError, arrow_parser};
+use crate::printers::arrow_printer;
+use crate::{CsvRecord, CsvRecords};
+
+use std::io::{self, Read, Write};
+
+/// Список банковских операций, представленных в формате `arrow ipc`.
+///
+/// Суммы хранятся как `Decimal128(18, 2)`, даты как `Date32`, банки как словарные строки.
+/// Конвертируется только в [CsvRecords] и обратно.
+#[derive(Debug)]
+pub struct ArrowRecords {
+    records: Vec<CsvRecord>,
+    options: ArrowOptions,
+}
+
+/// Настройки записи `arrow ipc`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArrowOptions {
+    /// Максимальное количество строк в одном батче.
+    pub batch_size: usize,
+    /// Алгоритм сжатия батчей, поддерживаются только [Compression::Lz4] и [Compression::Zstd].
+    pub compression: Compression,
+}
+
+impl Default for ArrowOptions {
+    fn default() -> Self {
+        Self {
+            batch_size: 64 * 1024,
+            compression: Compression::default(),
+        }
+    }
+}
+
+impl ArrowRecords {
+    pub fn list(&self) -> &Vec<CsvRecord> {
+        &self.records
+    }
+
+    pub fn into_parts(self) -> Vec<CsvRecord> {
+        self.records
+    }
+
+    pub fn options(&self) -> &ArrowOptions {
+        &self.options
+    }
+
+    /// Задаёт настройки, с которыми операции будут записаны.
+    pub fn with_options(mut self, options: ArrowOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+impl From<Vec<CsvRecord>> for ArrowRecords {
+    fn from(value: Vec<CsvRecord>) -> Self {
+        Self {
+            records: value,
+            options: ArrowOptions::default(),
+        }
+    }
+}
+
+impl From<CsvRecords> for ArrowRecords {
+    fn from(value: CsvRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl From<ArrowRecords> for CsvRecords {
+    fn from(value: ArrowRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl Parse<ArrowRecords> for ArrowRecords {
+    fn parse(reader: impl Read) -> Result<Self, ParseError> {
+        arrow_parser::parse(reader)
+    }
+}
+
+impl Print for &ArrowRecords {
+    fn print(&self, writer: impl Write) -> Result<(), io::Error> {
+        arrow_printer::print(writer, self)
+    }
+}
@@ -0,0 +1,75 @@
+//! Модуль содержит функционал, связанный со списком операций в формате `cbor` [CborRecords].
+//!
+//! # Раскладка полей
+//!
+//! Список операций записывается массивом, каждая операция картой с текстовыми ключами:
+//!
+//! | ключ                   | тип     | пример               |
+//! |------------------------|---------|----------------------|
+//! | `from_client`          | str     | `Alice`              |
+//! | `from_bank`            | str     | `bank_a`             |
+//! | `to_client`            | str     | `Bob`                |
+//! | `to_bank`              | str     | `bank_b`             |
+//! | `transaction`          | str     | `123`                |
+//! | `amount`               | float   | `500.05`             |
+//! | `date`                 | str     | `24-01-2025`         |
+//! | `description`          | str     | `Оплата по счёту 17` |
+//! | `end_to_end_id`        | str     | `E2E-0001`           |
+//! | `remittance_reference` | str     | `INV-17`             |
+//! | `value_date`           | str     | `27-01-2025`         |
+//!
+//! Ключи совпадают с заголовками `csv`, порядок ключей не важен при чтении.
+//! Последние четыре ключа необязательные и записываются, только если поле заполнено.
+//! Сумма записывается самым коротким float, который хранит её без потерь.
+
+use super::{Parse, Print};
+use crate::parsers::{ParseError, cbor_parser};
+use crate::printers::cbor_printer;
+use crate::{CsvRecord, CsvRecords};
+
+use std::io::{self, Read, Write};
+
+/// Список банковских операций, представленных в формате `cbor`.
+/// Конвертируется только в [CsvRecords] и обратно.
+#[derive(Debug)]
+pub struct CborRecords(Vec<CsvRecord>);
+
+impl CborRecords {
+    pub fn list(&self) -> &Vec<CsvRecord> {
+        &self.0
+    }
+
+    pub fn into_parts(self) -> Vec<CsvRecord> {
+        self.0
+    }
+}
+
+impl From<Vec<CsvRecord>> for CborRecords {
+    fn from(value: Vec<CsvRecord>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<CsvRecords> for CborRecords {
+    fn from(value: CsvRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl From<CborRecords> for CsvRecords {
+    fn from(value: CborRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl Parse<CborRecords> for CborRecords {
+    fn parse(reader: impl Read) -> Result<Self, ParseError> {
+        cbor_parser::parse(reader)
+    }
+}
+
+impl Print for &CborRecords {
+    fn print(&self, writer: impl Write) -> Result<(), io::Error> {
+        cbor_printer::print(writer, self)
+    }
+}
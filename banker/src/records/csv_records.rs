@@ -1,6 +1,7 @@
 //! Модуль содержит функционал, связанный со списком операций в формате `csv` [CsvRecords].
 
-use super::{Parse, Print};
+use super::{Columns, Parse, Print};
+use crate::fx::Conversion;
 use crate::parsers::{ParseError, csv_parser};
 use crate::printers::csv_printer;
 use crate::{JsonRecord, JsonRecords};
@@ -11,21 +12,37 @@ use std::io::{self, Read, Write};
 
 /// Список банковских операций, представленных в формате `csv`.
 #[derive(Debug)]
-pub struct CsvRecords(Vec<CsvRecord>);
+pub struct CsvRecords {
+    records: Vec<CsvRecord>,
+    columns: Columns,
+}
 
 impl CsvRecords {
     pub fn list(&self) -> &Vec<CsvRecord> {
-        &self.0
+        &self.records
     }
 
     pub fn into_parts(self) -> Vec<CsvRecord> {
-        self.0
+        self.records
+    }
+
+    pub fn columns(&self) -> &Columns {
+        &self.columns
+    }
+
+    /// Задаёт колонки, которые будут записаны, и их порядок.
+    pub fn with_columns(mut self, columns: Columns) -> Self {
+        self.columns = columns;
+        self
     }
 }
 
 impl From<Vec<CsvRecord>> for CsvRecords {
     fn from(value: Vec<CsvRecord>) -> Self {
-        Self(value)
+        Self {
+            records: value,
+            columns: Columns::default(),
+        }
     }
 }
 
@@ -42,7 +59,7 @@ impl From<JsonRecords> for CsvRecords {
 
 impl Parse<CsvRecords> for CsvRecords {
     fn parse(reader: impl Read) -> Result<Self, ParseError> {
-        Ok(csv_parser::parse(reader)?)
+        csv_parser::parse(reader)
     }
 }
 
@@ -53,7 +70,10 @@ impl Print for &CsvRecords {
 }
 
 /// Банковская операция, представленная в формете `csv`.
-#[derive(Debug, Deserialize, Serialize)]
+///
+/// [CsvRecord::new] заполняет обязательные поля, остальные можно задать после
+/// или через `..CsvRecord::new(..)`.
+#[derive(Debug, Default, Deserialize, Serialize)]
 #[cfg_attr(test, derive(PartialEq))]
 pub struct CsvRecord {
     pub from_client: String,
@@ -62,19 +82,117 @@ pub struct CsvRecord {
     pub to_bank: String,
     pub transaction: String,
     pub amount: f64,
+    /// Дата проводки.
     pub date: String,
+    /// Назначение платежа.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Сквозной идентификатор платежа, который передаётся между банками без изменений.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_to_end_id: Option<String>,
+    /// Ссылка для получателя, например номер счёта на оплату или договора.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remittance_reference: Option<String>,
+    /// Дата валютирования, если деньги зачислены не в день проводки.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value_date: Option<String>,
+    /// Откуда прочитана операция, если это важно, например при объединении файлов.
+    /// Записывается только в `csv` колонками `source_file` и `source_row`.
+    #[serde(skip)]
+    pub source: Option<Source>,
+    /// Курс, по которому пересчитана сумма, см. [crate::fx::convert].
+    #[serde(skip)]
+    pub conversion: Option<Conversion>,
+    /// Категория из [crate::categorize::Categories]. Записывается только в `csv` колонкой `category`.
+    #[serde(skip)]
+    pub category: Option<String>,
+}
+
+/// Источник операции: файл и номер операции в нём, начиная с 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Source {
+    pub file: String,
+    pub row: usize,
+}
+
+impl CsvRecord {
+    /// Операция с обязательными полями, необязательные не заполнены.
+    pub fn new(
+        from_client: impl Into<String>,
+        from_bank: impl Into<String>,
+        to_client: impl Into<String>,
+        to_bank: impl Into<String>,
+        transaction: impl Into<String>,
+        amount: f64,
+        date: impl Into<String>,
+    ) -> Self {
+        Self {
+            from_client: from_client.into(),
+            from_bank: from_bank.into(),
+            to_client: to_client.into(),
+            to_bank: to_bank.into(),
+            transaction: transaction.into(),
+            amount,
+            date: date.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Возвращает значение поля по его названию из [crate::records::FIELDS].
+    pub fn field(&self, name: &str) -> Option<String> {
+        match name {
+            "from_client" => Some(self.from_client.clone()),
+            "from_bank" => Some(self.from_bank.clone()),
+            "to_client" => Some(self.to_client.clone()),
+            "to_bank" => Some(self.to_bank.clone()),
+            "transaction" => Some(self.transaction.clone()),
+            "amount" => Some(self.amount.to_string()),
+            "date" => Some(self.date.clone()),
+            _ => None,
+        }
+    }
+
+    /// Возвращает значение необязательного поля по его названию из [crate::records::DETAILS].
+    /// [None], если поле не заполнено или такого поля нет.
+    pub fn detail(&self, name: &str) -> Option<&str> {
+        match name {
+            "description" => self.description.as_deref(),
+            "end_to_end_id" => self.end_to_end_id.as_deref(),
+            "remittance_reference" => self.remittance_reference.as_deref(),
+            "value_date" => self.value_date.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Возвращает необязательное поле по его названию из [crate::records::DETAILS]
+    /// для изменения. [None], если такого поля нет.
+    pub fn detail_mut(&mut self, name: &str) -> Option<&mut Option<String>> {
+        match name {
+            "description" => Some(&mut self.description),
+            "end_to_end_id" => Some(&mut self.end_to_end_id),
+            "remittance_reference" => Some(&mut self.remittance_reference),
+            "value_date" => Some(&mut self.value_date),
+            _ => None,
+        }
+    }
 }
 
 impl From<JsonRecord> for CsvRecord {
     fn from(json_record: JsonRecord) -> Self {
         Self {
-            from_client: json_record.sender,
-            from_bank: json_record.sender_bank,
-            to_client: json_record.reciever,
-            to_bank: json_record.reciever_bank,
-            transaction: json_record.transaction_id,
-            amount: json_record.quantity,
-            date: json_record.date,
+            description: json_record.description,
+            end_to_end_id: json_record.end_to_end_id,
+            remittance_reference: json_record.remittance_reference,
+            value_date: json_record.value_date,
+            ..Self::new(
+                json_record.sender,
+                json_record.sender_bank,
+                json_record.reciever,
+                json_record.reciever_bank,
+                json_record.transaction_id,
+                json_record.quantity,
+                json_record.date,
+            )
         }
     }
 }
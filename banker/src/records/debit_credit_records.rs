@@ -0,0 +1,99 @@
+//! Модуль содержит функционал, связанный со списком операций в формате `csv`
+//! с отдельными колонками списаний и поступлений [DebitCreditRecords].
+
+use super::{Parse, Print};
+use crate::direction::Party;
+use crate::parsers::{ParseError, debit_credit_parser};
+use crate::printers::debit_credit_printer;
+use crate::{CsvRecord, CsvRecords};
+
+use serde::Deserialize;
+
+use std::io::{self, Read, Write};
+
+/// Список банковских операций по счёту нашей стороны [Party], где вместо `amount`
+/// идут колонки `debit` и `credit`: у каждой операции заполнена ровно одна из них.
+///
+/// Для записи нужна наша сторона, задаётся через [DebitCreditRecords::with_party].
+/// При чтении сумма берётся из заполненной колонки, отправитель и получатель
+/// уже есть в строке.
+#[derive(Debug)]
+pub struct DebitCreditRecords {
+    records: Vec<CsvRecord>,
+    party: Party,
+}
+
+/// Строка `csv` с колонками списаний и поступлений. Колонки необязательных полей
+/// из [crate::records::DETAILS] идут после `date` и могут отсутствовать.
+#[derive(Debug, Deserialize)]
+pub(crate) struct DebitCreditRow {
+    pub(crate) from_client: String,
+    pub(crate) from_bank: String,
+    pub(crate) to_client: String,
+    pub(crate) to_bank: String,
+    pub(crate) transaction: String,
+    pub(crate) debit: Option<f64>,
+    pub(crate) credit: Option<f64>,
+    pub(crate) date: String,
+    #[serde(default)]
+    pub(crate) description: Option<String>,
+    #[serde(default)]
+    pub(crate) end_to_end_id: Option<String>,
+    #[serde(default)]
+    pub(crate) remittance_reference: Option<String>,
+    #[serde(default)]
+    pub(crate) value_date: Option<String>,
+}
+
+impl DebitCreditRecords {
+    pub fn list(&self) -> &Vec<CsvRecord> {
+        &self.records
+    }
+
+    pub fn into_parts(self) -> Vec<CsvRecord> {
+        self.records
+    }
+
+    pub fn party(&self) -> &Party {
+        &self.party
+    }
+
+    /// Задаёт нашу сторону, относительно которой операции делятся на списания и поступления.
+    pub fn with_party(mut self, party: Party) -> Self {
+        self.party = party;
+        self
+    }
+}
+
+impl From<Vec<CsvRecord>> for DebitCreditRecords {
+    fn from(value: Vec<CsvRecord>) -> Self {
+        Self {
+            records: value,
+            party: Party::default(),
+        }
+    }
+}
+
+impl From<CsvRecords> for DebitCreditRecords {
+    fn from(value: CsvRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl From<DebitCreditRecords> for CsvRecords {
+    fn from(value: DebitCreditRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl Parse<DebitCreditRecords> for DebitCreditRecords {
+    fn parse(reader: impl Read) -> Result<Self, ParseError> {
+        debit_credit_parser::parse(reader)
+    }
+}
+
+impl Print for &DebitCreditRecords {
+    fn print(&self, writer: impl Write) -> Result<(), io::Error> {
+        debit_credit_printer::print(writer, self)
+    }
+}
@@ -0,0 +1,221 @@
+//! Модуль содержит функционал, связанный со списком операций в формате
+//! с фиксированной шириной колонок [FixedRecords].
+
+use super::{DETAILS, FIELDS, Print};
+use crate::parsers::{ParseError, fixed_parser};
+use crate::printers::fixed_printer;
+use crate::{CsvRecord, CsvRecords};
+
+use serde::Deserialize;
+
+use std::io::{self, Read, Write};
+
+/// Список банковских операций в позиционном формате: одна строка на операцию,
+/// поля занимают фиксированные позиции, описанные в [FixedLayout].
+/// Без описания колонок формат не прочитать, поэтому вместо трейта [crate::records::Parse]
+/// используется [FixedRecords::parse_with_layout].
+/// Конвертируется только в [CsvRecords] и обратно.
+#[derive(Debug)]
+pub struct FixedRecords {
+    records: Vec<CsvRecord>,
+    layout: FixedLayout,
+}
+
+/// Описание расположения полей в строке.
+///
+/// Обычно загружается из `toml` файла:
+///
+/// ```toml
+/// [[column]]
+/// field = "amount"
+/// start = 1
+/// width = 12
+/// align = "right"
+/// pad = "0"
+/// decimals = 2
+///
+/// [[column]]
+/// field = "date"
+/// start = 13
+/// width = 8
+/// date_format = "%Y%m%d"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FixedLayout {
+    /// Колонки, по одной на каждое поле из [FIELDS] и не больше одной
+    /// на необязательное поле из [DETAILS].
+    #[serde(rename = "column")]
+    pub columns: Vec<FixedColumn>,
+}
+
+/// Колонка позиционного формата.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FixedColumn {
+    /// Поле операции из [FIELDS] или [DETAILS].
+    pub field: String,
+    /// Позиция первого символа колонки, начиная с 1.
+    pub start: usize,
+    /// Ширина колонки в символах.
+    pub width: usize,
+    /// Выравнивание значения внутри колонки.
+    #[serde(default)]
+    pub align: Align,
+    /// Символ, которым колонка дополняется до ширины.
+    #[serde(default = "default_pad")]
+    pub pad: char,
+    /// Количество подразумеваемых знаков после запятой, только для `amount`:
+    /// при `decimals = 2` значение `0000050005` означает `500.05`.
+    #[serde(default)]
+    pub decimals: Option<u32>,
+    /// Формат даты в колонке в синтаксисе `chrono`, только для `date`.
+    #[serde(default)]
+    pub date_format: Option<String>,
+}
+
+/// Выравнивание значения в колонке.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Align {
+    #[default]
+    Left,
+    Right,
+}
+
+fn default_pad() -> char {
+    ' '
+}
+
+impl FixedLayout {
+    /// Читает описание колонок из `toml`.
+    pub fn from_toml(layout: &str) -> Result<Self, ParseError> {
+        let layout: FixedLayout = toml::from_str(layout)
+            .map_err(|err| ParseError::FixedWidthParseError(err.to_string()))?;
+        layout
+            .validate()
+            .map_err(ParseError::FixedWidthParseError)?;
+        Ok(layout)
+    }
+
+    /// Проверяет, что каждое поле описано ровно одной колонкой, необязательное поле
+    /// не больше чем одной, и колонки не пересекаются.
+    pub fn validate(&self) -> Result<(), String> {
+        for field in FIELDS {
+            let count = self.columns.iter().filter(|c| c.field == field).count();
+            if count != 1 {
+                return Err(format!(
+                    "поле `{field}` должно быть описано одной колонкой, описано: {count}"
+                ));
+            }
+        }
+
+        for detail in DETAILS {
+            let count = self.columns.iter().filter(|c| c.field == detail).count();
+            if count > 1 {
+                return Err(format!(
+                    "поле `{detail}` описано несколькими колонками: {count}"
+                ));
+            }
+        }
+
+        for column in &self.columns {
+            let field = &column.field;
+            if !FIELDS.contains(&field.as_str()) && !DETAILS.contains(&field.as_str()) {
+                return Err(format!("неизвестное поле `{field}`"));
+            }
+            if column.start == 0 || column.width == 0 {
+                return Err(format!(
+                    "у колонки `{field}` позиция и ширина должны быть больше нуля"
+                ));
+            }
+            if column.decimals.is_some() && field != "amount" {
+                return Err(format!(
+                    "`decimals` допустим только для `amount`, а не `{field}`"
+                ));
+            }
+            if column.date_format.is_some() && field != "date" {
+                return Err(format!(
+                    "`date_format` допустим только для `date`, а не `{field}`"
+                ));
+            }
+        }
+
+        let mut columns: Vec<&FixedColumn> = self.columns.iter().collect();
+        columns.sort_by_key(|c| c.start);
+        for pair in columns.windows(2) {
+            if pair[0].start + pair[0].width > pair[1].start {
+                return Err(format!(
+                    "колонки `{}` и `{}` пересекаются",
+                    pair[0].field, pair[1].field
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Длина строки, в которую помещаются все колонки.
+    pub fn line_width(&self) -> usize {
+        self.columns
+            .iter()
+            .map(|c| c.start + c.width - 1)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl FixedRecords {
+    pub fn list(&self) -> &Vec<CsvRecord> {
+        &self.records
+    }
+
+    pub fn into_parts(self) -> Vec<CsvRecord> {
+        self.records
+    }
+
+    pub fn layout(&self) -> &FixedLayout {
+        &self.layout
+    }
+
+    /// Задаёт расположение колонок, с которым операции будут записаны.
+    pub fn with_layout(mut self, layout: FixedLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Парсит данные по заданному расположению колонок.
+    pub fn parse_with_layout(
+        reader: impl Read,
+        layout: FixedLayout,
+    ) -> Result<FixedRecords, ParseError> {
+        fixed_parser::parse(reader, layout)
+    }
+}
+
+impl From<Vec<CsvRecord>> for FixedRecords {
+    fn from(value: Vec<CsvRecord>) -> Self {
+        Self {
+            records: value,
+            layout: FixedLayout::default(),
+        }
+    }
+}
+
+impl From<CsvRecords> for FixedRecords {
+    fn from(value: CsvRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl From<FixedRecords> for CsvRecords {
+    fn from(value: FixedRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl Print for &FixedRecords {
+    fn print(&self, writer: impl Write) -> Result<(), io::Error> {
+        fixed_printer::print(writer, self)
+    }
+}
@@ -0,0 +1,211 @@
+//! Модуль содержит функционал, связанный с журналами plain-text бухгалтерии:
+//! [LedgerRecords] для Ledger/hledger и [BeancountRecords] для Beancount.
+//!
+//! Журналы только записываются: каждая операция превращается в транзакцию
+//! с двумя проводками между счетами отправителя и получателя.
+
+use super::{Print, parse_date, to_cents};
+use crate::printers::{beancount_printer, ledger_printer};
+use crate::{CsvRecord, CsvRecords};
+
+use chrono::NaiveDate;
+
+use std::io::{self, Write};
+
+/// Настройки записи журналов.
+///
+/// Счета задаются шаблонами, в которых `{client}` и `{bank}` заменяются
+/// на клиента и банк соответствующей стороны операции. Части счёта,
+/// разделённые `:`, очищаются от символов, недопустимых в названиях счетов.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalOptions {
+    /// Шаблон счёта отправителя.
+    pub from_account: String,
+    /// Шаблон счёта получателя.
+    pub to_account: String,
+    /// Валюта сумм, если они не пересчитаны, см. [crate::fx::Conversion].
+    pub commodity: String,
+}
+
+impl Default for JournalOptions {
+    fn default() -> Self {
+        Self {
+            from_account: "Assets:{bank}:{client}".into(),
+            to_account: "Assets:{bank}:{client}".into(),
+            commodity: "RUB".into(),
+        }
+    }
+}
+
+/// Синтаксис, под который очищаются названия счетов.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Syntax {
+    Ledger,
+    Beancount,
+}
+
+impl JournalOptions {
+    /// Возвращает счета отправителя и получателя операции.
+    pub(crate) fn accounts(&self, record: &CsvRecord, syntax: Syntax) -> (String, String) {
+        let from = account(
+            &self.from_account,
+            &record.from_client,
+            &record.from_bank,
+            syntax,
+        );
+        let to = account(&self.to_account, &record.to_client, &record.to_bank, syntax);
+        (from, to)
+    }
+}
+
+/// Возвращает дату операции, ошибка парсинга превращается в ошибку записи.
+pub(crate) fn journal_date(record: &CsvRecord) -> Result<NaiveDate, io::Error> {
+    parse_date(&record.date).map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("некорректная дата `{}`: {err}", record.date),
+        )
+    })
+}
+
+/// Дата курса пересчёта в формате журнала, некорректная записывается как есть.
+pub(crate) fn rate_date(date: &str) -> String {
+    parse_date(date).map_or_else(|_| date.into(), |date| date.to_string())
+}
+
+/// Сумма в формате журнала с двумя знаками после запятой. Сумма округляется до копеек,
+/// поэтому округлённая до нуля отрицательная сумма записывается `0.00`, а не `-0.00`.
+pub(crate) fn journal_amount(amount: f64) -> String {
+    let cents = to_cents(amount);
+    let sign = if cents < 0 { "-" } else { "" };
+    format!("{sign}{}.{:02}", cents.abs() / 100, cents.abs() % 100)
+}
+
+fn account(template: &str, client: &str, bank: &str, syntax: Syntax) -> String {
+    template
+        .split(':')
+        .map(|part| part.replace("{client}", client).replace("{bank}", bank))
+        .map(|part| sanitize(&part, syntax))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+/// Оставляет в части счёта только буквы, цифры и дефисы (в Ledger ещё и
+/// подчёркивания), для Beancount дополнительно делает первую букву заглавной.
+fn sanitize(part: &str, syntax: Syntax) -> String {
+    let allowed =
+        |c: char| c.is_alphanumeric() || c == '-' || (syntax == Syntax::Ledger && c == '_');
+
+    let mut sanitized = String::new();
+    for c in part.trim().chars() {
+        if allowed(c) {
+            sanitized.push(c);
+        } else if !sanitized.ends_with('-') {
+            sanitized.push('-');
+        }
+    }
+    let sanitized = sanitized.trim_matches('-');
+
+    let mut chars = sanitized.chars();
+    match (chars.next(), syntax) {
+        (None, _) => "Unknown".into(),
+        (Some(first), Syntax::Beancount) => first.to_uppercase().chain(chars).collect(),
+        (Some(_), Syntax::Ledger) => sanitized.into(),
+    }
+}
+
+/// Список банковских операций для записи в формате `ledger`.
+#[derive(Debug)]
+pub struct LedgerRecords {
+    records: Vec<CsvRecord>,
+    options: JournalOptions,
+}
+
+impl LedgerRecords {
+    pub fn list(&self) -> &Vec<CsvRecord> {
+        &self.records
+    }
+
+    pub fn into_parts(self) -> Vec<CsvRecord> {
+        self.records
+    }
+
+    pub fn options(&self) -> &JournalOptions {
+        &self.options
+    }
+
+    /// Задаёт настройки, с которыми операции будут записаны.
+    pub fn with_options(mut self, options: JournalOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+impl From<Vec<CsvRecord>> for LedgerRecords {
+    fn from(value: Vec<CsvRecord>) -> Self {
+        Self {
+            records: value,
+            options: JournalOptions::default(),
+        }
+    }
+}
+
+impl From<CsvRecords> for LedgerRecords {
+    fn from(value: CsvRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl Print for &LedgerRecords {
+    fn print(&self, writer: impl Write) -> Result<(), io::Error> {
+        ledger_printer::print(writer, self)
+    }
+}
+
+/// Список банковских операций для записи в формате `beancount`.
+#[derive(Debug)]
+pub struct BeancountRecords {
+    records: Vec<CsvRecord>,
+    options: JournalOptions,
+}
+
+impl BeancountRecords {
+    pub fn list(&self) -> &Vec<CsvRecord> {
+        &self.records
+    }
+
+    pub fn into_parts(self) -> Vec<CsvRecord> {
+        self.records
+    }
+
+    pub fn options(&self) -> &JournalOptions {
+        &self.options
+    }
+
+    /// Задаёт настройки, с которыми операции будут записаны.
+    pub fn with_options(mut self, options: JournalOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+impl From<Vec<CsvRecord>> for BeancountRecords {
+    fn from(value: Vec<CsvRecord>) -> Self {
+        Self {
+            records: value,
+            options: JournalOptions::default(),
+        }
+    }
+}
+
+impl From<CsvRecords> for BeancountRecords {
+    fn from(value: CsvRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl Print for &BeancountRecords {
+    fn print(&self, writer: impl Write) -> Result<(), io::Error> {
+        beancount_printer::print(writer, self)
+    }
+}
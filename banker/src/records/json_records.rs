@@ -42,7 +42,7 @@ impl From<CsvRecords> for JsonRecords {
 
 impl Parse<JsonRecords> for JsonRecords {
     fn parse(reader: impl Read) -> Result<Self, ParseError> {
-        Ok(json_parser::parse(reader)?)
+        json_parser::parse(reader)
     }
 }
 
@@ -63,6 +63,14 @@ pub struct JsonRecord {
     pub transaction_id: String,
     pub quantity: f64,
     pub date: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_to_end_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remittance_reference: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value_date: Option<String>,
 }
 
 impl From<CsvRecord> for JsonRecord {
@@ -75,6 +83,10 @@ impl From<CsvRecord> for JsonRecord {
             transaction_id: csv_record.transaction,
             quantity: csv_record.amount,
             date: csv_record.date,
+            description: csv_record.description,
+            end_to_end_id: csv_record.end_to_end_id,
+            remittance_reference: csv_record.remittance_reference,
+            value_date: csv_record.value_date,
         }
     }
 }
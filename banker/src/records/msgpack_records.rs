@@ -0,0 +1,74 @@
+//! Модуль содержит функционал, связанный со списком операций в формате `msgpack` [MsgpackRecords].
+//!
+//! # Раскладка полей
+//!
+//! Список операций записывается массивом, каждая операция картой с текстовыми ключами:
+//!
+//! | ключ                   | тип     | пример               |
+//! |------------------------|---------|----------------------|
+//! | `from_client`          | str     | `Alice`              |
+//! | `from_bank`            | str     | `bank_a`             |
+//! | `to_client`            | str     | `Bob`                |
+//! | `to_bank`              | str     | `bank_b`             |
+//! | `transaction`          | str     | `123`                |
+//! | `amount`               | float64 | `500.05`             |
+//! | `date`                 | str     | `24-01-2025`         |
+//! | `description`          | str     | `Оплата по счёту 17` |
+//! | `end_to_end_id`        | str     | `E2E-0001`           |
+//! | `remittance_reference` | str     | `INV-17`             |
+//! | `value_date`           | str     | `27-01-2025`         |
+//!
+//! Ключи совпадают с заголовками `csv`, порядок ключей не важен при чтении.
+//! Последние четыре ключа необязательные и записываются, только если поле заполнено.
+
+use super::{Parse, Print};
+use crate::parsers::{ParseError, msgpack_parser};
+use crate::printers::msgpack_printer;
+use crate::{CsvRecord, CsvRecords};
+
+use std::io::{self, Read, Write};
+
+/// Список банковских операций, представленных в формате `msgpack`.
+/// Конвертируется только в [CsvRecords] и обратно.
+#[derive(Debug)]
+pub struct MsgpackRecords(Vec<CsvRecord>);
+
+impl MsgpackRecords {
+    pub fn list(&self) -> &Vec<CsvRecord> {
+        &self.0
+    }
+
+    pub fn into_parts(self) -> Vec<CsvRecord> {
+        self.0
+    }
+}
+
+impl From<Vec<CsvRecord>> for MsgpackRecords {
+    fn from(value: Vec<CsvRecord>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<CsvRecords> for MsgpackRecords {
+    fn from(value: CsvRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl From<MsgpackRecords> for CsvRecords {
+    fn from(value: MsgpackRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl Parse<MsgpackRecords> for MsgpackRecords {
+    fn parse(reader: impl Read) -> Result<Self, ParseError> {
+        msgpack_parser::parse(reader)
+    }
+}
+
+impl Print for &MsgpackRecords {
+    fn print(&self, writer: impl Write) -> Result<(), io::Error> {
+        msgpack_printer::print(writer, self)
+    }
+}
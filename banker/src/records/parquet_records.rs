@@ -0,0 +1,89 @@
+//! Модуль содержит функционал, связанный со списком операций в формате `parquet` [ParquetRecords].
+
+use super::{Compression, Parse, Print};
+use crate::parsers::{ParseError, parquet_parser};
+use crate::printers::parquet_printer;
+use crate::{CsvRecord, CsvRecords};
+
+use std::io::{self, Read, Write};
+
+/// Список банковских операций, представленных в формате `parquet`.
+///
+/// Суммы хранятся как `Decimal128(18, 2)`, даты как `Date32`, банки как словарные строки.
+/// Конвертируется только в [CsvRecords] и обратно.
+#[derive(Debug)]
+pub struct ParquetRecords {
+    records: Vec<CsvRecord>,
+    options: ParquetOptions,
+}
+
+/// Настройки записи `parquet`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParquetOptions {
+    /// Максимальное количество строк в одной группе строк.
+    pub row_group_size: usize,
+    /// Алгоритм сжатия колонок.
+    pub compression: Compression,
+}
+
+impl Default for ParquetOptions {
+    fn default() -> Self {
+        Self {
+            row_group_size: 1024 * 1024,
+            compression: Compression::default(),
+        }
+    }
+}
+
+impl ParquetRecords {
+    pub fn list(&self) -> &Vec<CsvRecord> {
+        &self.records
+    }
+
+    pub fn into_parts(self) -> Vec<CsvRecord> {
+        self.records
+    }
+
+    pub fn options(&self) -> &ParquetOptions {
+        &self.options
+    }
+
+    /// Задаёт настройки, с которыми операции будут записаны.
+    pub fn with_options(mut self, options: ParquetOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+impl From<Vec<CsvRecord>> for ParquetRecords {
+    fn from(value: Vec<CsvRecord>) -> Self {
+        Self {
+            records: value,
+            options: ParquetOptions::default(),
+        }
+    }
+}
+
+impl From<CsvRecords> for ParquetRecords {
+    fn from(value: CsvRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl From<ParquetRecords> for CsvRecords {
+    fn from(value: ParquetRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl Parse<ParquetRecords> for ParquetRecords {
+    fn parse(reader: impl Read) -> Result<Self, ParseError> {
+        parquet_parser::parse(reader)
+    }
+}
+
+impl Print for &ParquetRecords {
+    fn print(&self, writer: impl Write) -> Result<(), io::Error> {
+        parquet_printer::print(writer, self)
+    }
+}
@@ -0,0 +1,150 @@
+//! Модуль содержит функционал, связанный с отчётами для чтения человеком:
+//! текстовой таблицей [TableRecords], таблицей Markdown [MarkdownRecords]
+//! и HTML отчётом [HtmlRecords].
+//!
+//! Отчёты только записываются.
+
+use super::{Columns, FIELDS, Print};
+use crate::printers::{html_printer, markdown_printer, table_printer};
+use crate::{CsvRecord, CsvRecords};
+
+use std::io::{self, Write};
+
+/// Настройки текстовой таблицы.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TableOptions {
+    /// Максимальная ширина строки, обычно ширина терминала. Если таблица не помещается,
+    /// самые широкие текстовые колонки обрезаются.
+    pub max_width: Option<usize>,
+    /// Колонки таблицы и их порядок.
+    pub columns: Columns,
+}
+
+/// Список банковских операций для записи текстовой таблицей с выровненными колонками.
+#[derive(Debug)]
+pub struct TableRecords {
+    records: Vec<CsvRecord>,
+    options: TableOptions,
+}
+
+impl TableRecords {
+    pub fn list(&self) -> &Vec<CsvRecord> {
+        &self.records
+    }
+
+    pub fn into_parts(self) -> Vec<CsvRecord> {
+        self.records
+    }
+
+    pub fn options(&self) -> &TableOptions {
+        &self.options
+    }
+
+    /// Задаёт настройки, с которыми операции будут записаны.
+    pub fn with_options(mut self, options: TableOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+impl From<Vec<CsvRecord>> for TableRecords {
+    fn from(value: Vec<CsvRecord>) -> Self {
+        Self {
+            records: value,
+            options: TableOptions::default(),
+        }
+    }
+}
+
+impl From<CsvRecords> for TableRecords {
+    fn from(value: CsvRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl Print for &TableRecords {
+    fn print(&self, writer: impl Write) -> Result<(), io::Error> {
+        table_printer::print(writer, self)
+    }
+}
+
+/// Список банковских операций для записи таблицей Markdown, например для вставки в тикет.
+#[derive(Debug)]
+pub struct MarkdownRecords(Vec<CsvRecord>);
+
+impl MarkdownRecords {
+    pub fn list(&self) -> &Vec<CsvRecord> {
+        &self.0
+    }
+
+    pub fn into_parts(self) -> Vec<CsvRecord> {
+        self.0
+    }
+}
+
+impl From<Vec<CsvRecord>> for MarkdownRecords {
+    fn from(value: Vec<CsvRecord>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<CsvRecords> for MarkdownRecords {
+    fn from(value: CsvRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl Print for &MarkdownRecords {
+    fn print(&self, writer: impl Write) -> Result<(), io::Error> {
+        markdown_printer::print(writer, self)
+    }
+}
+
+/// Список банковских операций для записи самодостаточным HTML отчётом
+/// с итогами по банкам.
+#[derive(Debug)]
+pub struct HtmlRecords(Vec<CsvRecord>);
+
+impl HtmlRecords {
+    pub fn list(&self) -> &Vec<CsvRecord> {
+        &self.0
+    }
+
+    pub fn into_parts(self) -> Vec<CsvRecord> {
+        self.0
+    }
+}
+
+impl From<Vec<CsvRecord>> for HtmlRecords {
+    fn from(value: Vec<CsvRecord>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<CsvRecords> for HtmlRecords {
+    fn from(value: CsvRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl Print for &HtmlRecords {
+    fn print(&self, writer: impl Write) -> Result<(), io::Error> {
+        html_printer::print(writer, self)
+    }
+}
+
+/// Возвращает значения полей операции в порядке [FIELDS], сумма с двумя знаками.
+pub(crate) fn report_cells(record: &CsvRecord) -> Vec<String> {
+    FIELDS
+        .iter()
+        .map(|&field| match field {
+            "amount" => format_amount(record.amount),
+            field => record.field(field).unwrap_or_default(),
+        })
+        .collect()
+}
+
+/// Форматирует сумму с двумя знаками после запятой.
+pub(crate) fn format_amount(amount: f64) -> String {
+    format!("{amount:.2}")
+}
@@ -0,0 +1,89 @@
+//! Модуль содержит функционал, связанный со списком операций в базе `sqlite` [SqliteRecords].
+
+use crate::parsers::{ParseError, sqlite_parser};
+use crate::printers::sqlite_printer;
+use crate::{CsvRecord, CsvRecords};
+
+use std::io;
+use std::path::Path;
+
+/// Таблица, в которой хранятся операции.
+pub const SQLITE_TABLE: &str = "transactions";
+
+/// Список банковских операций из таблицы [SQLITE_TABLE] базы `sqlite`.
+///
+/// Таблица строгая (`STRICT`): `transaction_id`, клиенты и банки хранятся как `TEXT`,
+/// сумма как `REAL`, дата как `TEXT` в формате `YYYY-MM-DD`, чтобы её можно было
+/// сравнивать в запросах. Есть индексы по дате, клиентам и банкам.
+///
+/// База работает с файлом, а не с потоком, поэтому вместо трейтов
+/// [crate::records::Parse] и [crate::records::Print] используются
+/// [SqliteRecords::load] и [SqliteRecords::save].
+/// Конвертируется только в [CsvRecords] и обратно.
+#[derive(Debug)]
+pub struct SqliteRecords {
+    records: Vec<CsvRecord>,
+    mode: SqliteMode,
+}
+
+/// Что делать с операциями, уже лежащими в таблице.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SqliteMode {
+    /// Пересоздать таблицу.
+    #[default]
+    Replace,
+    /// Дописать операции к существующим.
+    Append,
+}
+
+impl SqliteRecords {
+    pub fn list(&self) -> &Vec<CsvRecord> {
+        &self.records
+    }
+
+    pub fn into_parts(self) -> Vec<CsvRecord> {
+        self.records
+    }
+
+    pub fn mode(&self) -> SqliteMode {
+        self.mode
+    }
+
+    /// Задаёт режим, в котором операции будут сохранены.
+    pub fn with_mode(mut self, mode: SqliteMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Читает операции из базы. Условие `filter` подставляется в `WHERE` как есть,
+    /// например `amount > 1000 AND date >= '2025-01-01'`.
+    pub fn load(path: &Path, filter: Option<&str>) -> Result<SqliteRecords, ParseError> {
+        sqlite_parser::parse(path, filter)
+    }
+
+    /// Сохраняет операции в базу, создавая её при необходимости.
+    pub fn save(&self, path: &Path) -> Result<(), io::Error> {
+        sqlite_printer::print(path, self)
+    }
+}
+
+impl From<Vec<CsvRecord>> for SqliteRecords {
+    fn from(value: Vec<CsvRecord>) -> Self {
+        Self {
+            records: value,
+            mode: SqliteMode::default(),
+        }
+    }
+}
+
+impl From<CsvRecords> for SqliteRecords {
+    fn from(value: CsvRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl From<SqliteRecords> for CsvRecords {
+    fn from(value: SqliteRecords) -> Self {
+        value.into_parts().into()
+    }
+}
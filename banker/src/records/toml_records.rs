@@ -0,0 +1,77 @@
+//! Модуль содержит функционал, связанный со списком операций в формате `toml` [TomlRecords].
+//!
+//! Операции записываются массивом таблиц `[[transaction]]` с ключами как в заголовке `csv`:
+//!
+//! ```toml
+//! [[transaction]]
+//! from_client = "Alice"
+//! from_bank = "bank_a"
+//! to_client = "Bob"
+//! to_bank = "bank_b"
+//! transaction = "123"
+//! amount = 500.05
+//! date = "24-01-2025"
+//! ```
+
+use super::{Parse, Print};
+use crate::parsers::{ParseError, toml_parser};
+use crate::printers::toml_printer;
+use crate::{CsvRecord, CsvRecords};
+
+use serde::{Deserialize, Serialize};
+
+use std::io::{self, Read, Write};
+
+/// Список банковских операций, представленных в формате `toml`.
+/// Удобен для фикстур и небольших списков, которые правят руками.
+/// Конвертируется только в [CsvRecords] и обратно.
+#[derive(Debug)]
+pub struct TomlRecords(Vec<CsvRecord>);
+
+/// Документ `toml`: корневая таблица с массивом операций.
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct TomlDocument<T> {
+    #[serde(default)]
+    pub(crate) transaction: T,
+}
+
+impl TomlRecords {
+    pub fn list(&self) -> &Vec<CsvRecord> {
+        &self.0
+    }
+
+    pub fn into_parts(self) -> Vec<CsvRecord> {
+        self.0
+    }
+}
+
+impl From<Vec<CsvRecord>> for TomlRecords {
+    fn from(value: Vec<CsvRecord>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<CsvRecords> for TomlRecords {
+    fn from(value: CsvRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl From<TomlRecords> for CsvRecords {
+    fn from(value: TomlRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl Parse<TomlRecords> for TomlRecords {
+    fn parse(reader: impl Read) -> Result<Self, ParseError> {
+        toml_parser::parse(reader)
+    }
+}
+
+impl Print for &TomlRecords {
+    fn print(&self, writer: impl Write) -> Result<(), io::Error> {
+        toml_printer::print(writer, self)
+    }
+}
@@ -0,0 +1,158 @@
+//! Модуль содержит функционал, связанный со списком операций в формате `xml` [XmlRecords].
+
+use super::{DETAILS, FIELDS, Parse, Print};
+use crate::parsers::{ParseError, xml_parser};
+use crate::printers::xml_printer;
+use crate::{CsvRecord, CsvRecords};
+
+use std::collections::BTreeMap;
+use std::io::{self, Read, Write};
+
+/// Список банковских операций, представленных в формате `xml`.
+///
+/// Каждая операция записывается отдельным элементом, поля операции вложенными
+/// элементами. Названия элементов задаются через [XmlOptions].
+/// Конвертируется только в [CsvRecords] и обратно.
+#[derive(Debug)]
+pub struct XmlRecords {
+    records: Vec<CsvRecord>,
+    options: XmlOptions,
+}
+
+/// Названия элементов `xml`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XmlOptions {
+    /// Корневой элемент со списком операций.
+    pub root: String,
+    /// Элемент одной операции.
+    pub record: String,
+    /// Переименования полей операции: название поля из [FIELDS] или [DETAILS] -> название элемента.
+    /// Поля без переименования записываются под своими названиями.
+    pub elements: BTreeMap<String, String>,
+}
+
+impl Default for XmlOptions {
+    fn default() -> Self {
+        Self {
+            root: "records".into(),
+            record: "record".into(),
+            elements: BTreeMap::new(),
+        }
+    }
+}
+
+impl XmlOptions {
+    /// Возвращает название элемента для поля операции.
+    pub fn element<'a>(&'a self, field: &'a str) -> &'a str {
+        self.elements.get(field).map_or(field, String::as_str)
+    }
+
+    /// Проверяет, что все названия являются корректными именами `xml` и не повторяются.
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(field) = self
+            .elements
+            .keys()
+            .find(|f| !FIELDS.contains(&f.as_str()) && !DETAILS.contains(&f.as_str()))
+        {
+            return Err(format!("неизвестное поле `{field}`"));
+        }
+
+        let mut names = vec![self.root.as_str(), self.record.as_str()];
+        names.extend(
+            FIELDS
+                .iter()
+                .chain(&DETAILS)
+                .map(|field| self.element(field)),
+        );
+
+        if let Some(name) = names.iter().find(|name| !is_xml_name(name)) {
+            return Err(format!("некорректное название элемента `{name}`"));
+        }
+
+        let elements = &names[2..];
+        for (i, name) in elements.iter().enumerate() {
+            if elements[i + 1..].contains(name) {
+                return Err(format!("элемент `{name}` задан для нескольких полей"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Записывает `xsd` схему, которой соответствует `xml` с этими названиями элементов.
+    pub fn print_schema(&self, writer: impl Write) -> Result<(), io::Error> {
+        xml_printer::print_schema(writer, self)
+    }
+}
+
+fn is_xml_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+
+    (first.is_alphabetic() || first == '_')
+        && chars.all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        && !name.to_lowercase().starts_with("xml")
+}
+
+impl XmlRecords {
+    pub fn list(&self) -> &Vec<CsvRecord> {
+        &self.records
+    }
+
+    pub fn into_parts(self) -> Vec<CsvRecord> {
+        self.records
+    }
+
+    pub fn options(&self) -> &XmlOptions {
+        &self.options
+    }
+
+    /// Задаёт названия элементов, с которыми операции будут записаны.
+    pub fn with_options(mut self, options: XmlOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Парсит данные с заданными названиями элементов.
+    pub fn parse_with_options(
+        reader: impl Read,
+        options: XmlOptions,
+    ) -> Result<XmlRecords, ParseError> {
+        xml_parser::parse(reader, options)
+    }
+}
+
+impl From<Vec<CsvRecord>> for XmlRecords {
+    fn from(value: Vec<CsvRecord>) -> Self {
+        Self {
+            records: value,
+            options: XmlOptions::default(),
+        }
+    }
+}
+
+impl From<CsvRecords> for XmlRecords {
+    fn from(value: CsvRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl From<XmlRecords> for CsvRecords {
+    fn from(value: XmlRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl Parse<XmlRecords> for XmlRecords {
+    fn parse(reader: impl Read) -> Result<Self, ParseError> {
+        xml_parser::parse(reader, XmlOptions::default())
+    }
+}
+
+impl Print for &XmlRecords {
+    fn print(&self, writer: impl Write) -> Result<(), io::Error> {
+        xml_printer::print(writer, self)
+    }
+}
@@ -0,0 +1,66 @@
+//! Модуль содержит функционал, связанный со списком операций в формате `yaml` [YamlRecords].
+//!
+//! Документ является последовательностью отображений с ключами как в заголовке `csv`:
+//!
+//! ```yaml
+//! - from_client: Alice
+//!   from_bank: bank_a
+//!   to_client: Bob
+//!   to_bank: bank_b
+//!   transaction: '123'
+//!   amount: 500.05
+//!   date: 24-01-2025
+//! ```
+
+use super::{Parse, Print};
+use crate::parsers::{ParseError, yaml_parser};
+use crate::printers::yaml_printer;
+use crate::{CsvRecord, CsvRecords};
+
+use std::io::{self, Read, Write};
+
+/// Список банковских операций, представленных в формате `yaml`.
+/// Удобен для фикстур и небольших списков, которые правят руками.
+/// Конвертируется только в [CsvRecords] и обратно.
+#[derive(Debug)]
+pub struct YamlRecords(Vec<CsvRecord>);
+
+impl YamlRecords {
+    pub fn list(&self) -> &Vec<CsvRecord> {
+        &self.0
+    }
+
+    pub fn into_parts(self) -> Vec<CsvRecord> {
+        self.0
+    }
+}
+
+impl From<Vec<CsvRecord>> for YamlRecords {
+    fn from(value: Vec<CsvRecord>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<CsvRecords> for YamlRecords {
+    fn from(value: CsvRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl From<YamlRecords> for CsvRecords {
+    fn from(value: YamlRecords) -> Self {
+        value.into_parts().into()
+    }
+}
+
+impl Parse<YamlRecords> for YamlRecords {
+    fn parse(reader: impl Read) -> Result<Self, ParseError> {
+        yaml_parser::parse(reader)
+    }
+}
+
+impl Print for &YamlRecords {
+    fn print(&self, writer: impl Write) -> Result<(), io::Error> {
+        yaml_printer::print(writer, self)
+    }
+}
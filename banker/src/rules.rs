@@ -0,0 +1,383 @@
+//! Модуль содержит движок бизнес-правил для операций.
+//!
+//! Правила описываются в `toml` и проверяются на уже распарсенных операциях:
+//!
+//! ```toml
+//! [[rule]]
+//! name = "сумма положительная"
+//! field = "amount"
+//! operator = ">"
+//! value = 0
+//!
+//! [[rule]]
+//! name = "лимит bank_a"
+//! field = "amount"
+//! operator = "<"
+//! value = 1000000
+//! severity = "warning"
+//! when = { from_bank = "bank_a" }
+//!
+//! [[rule]]
+//! field = "date"
+//! operator = "<="
+//! value = "today"
+//!
+//! [[rule]]
+//! field = "from_client"
+//! operator = "!="
+//! other = "to_client"
+//!
+//! [[rule]]
+//! field = "transaction"
+//! operator = "unique"
+//! ```
+//!
+//! Правило описывает условие, которому операция должна соответствовать,
+//! каждое несоответствие попадает в отчёт [Report].
+
+use crate::parsers::ParseError;
+use crate::records::{CsvRecord, FIELDS, parse_date};
+use crate::value::{Value, kind, record_value};
+
+use chrono::{Days, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Набор правил.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Rules {
+    #[serde(rename = "rule", default)]
+    pub rules: Vec<Rule>,
+}
+
+/// Правило для одного поля операции.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Rule {
+    /// Название правила для отчёта. Если не задано, в отчёт попадает описание условия.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Проверяемое поле из [FIELDS].
+    pub field: String,
+    pub operator: Operator,
+    /// Значение, с которым сравнивается поле. Для `date` допустимо `today`.
+    #[serde(default)]
+    pub value: Option<RuleValue>,
+    /// Другое поле той же операции, с которым сравнивается поле.
+    #[serde(default)]
+    pub other: Option<String>,
+    #[serde(default)]
+    pub severity: Severity,
+    /// Правило применяется только к операциям с такими значениями полей.
+    #[serde(default)]
+    pub when: BTreeMap<String, String>,
+}
+
+/// Оператор сравнения.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum Operator {
+    #[serde(rename = "==")]
+    Eq,
+    #[serde(rename = "!=")]
+    Ne,
+    #[serde(rename = ">")]
+    Gt,
+    #[serde(rename = ">=")]
+    Ge,
+    #[serde(rename = "<")]
+    Lt,
+    #[serde(rename = "<=")]
+    Le,
+    /// Значение поля не повторяется у других операций.
+    #[serde(rename = "unique")]
+    Unique,
+}
+
+/// Значение в правиле: число или строка.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum RuleValue {
+    Number(f64),
+    Text(String),
+}
+
+/// Важность правила: ошибки делают набор операций некорректным, предупреждения нет.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+}
+
+/// Нарушение правила одной операцией.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Violation {
+    /// Название или описание нарушенного правила.
+    pub rule: String,
+    pub severity: Severity,
+    /// Номер операции в списке, начиная с 0.
+    pub index: usize,
+    /// Номер транзакции.
+    pub transaction: String,
+    pub message: String,
+}
+
+/// Отчёт о проверке операций по правилам.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Report {
+    pub violations: Vec<Violation>,
+}
+
+impl Rules {
+    /// Читает правила из `toml` и проверяет, что они корректны.
+    pub fn from_toml(rules: &str) -> Result<Self, ParseError> {
+        let rules: Rules =
+            toml::from_str(rules).map_err(|err| ParseError::RulesParseError(err.to_string()))?;
+        rules.validate().map_err(ParseError::RulesParseError)?;
+        Ok(rules)
+    }
+
+    /// Проверяет, что поля известны, а значения подходят к типам полей.
+    pub fn validate(&self) -> Result<(), String> {
+        let today = NaiveDate::default();
+        for rule in &self.rules {
+            let known = |field: &str| FIELDS.contains(&field);
+            if let Some(field) = [&rule.field]
+                .into_iter()
+                .chain(&rule.other)
+                .chain(rule.when.keys())
+                .find(|field| !known(field))
+            {
+                return Err(format!("неизвестное поле `{field}`"));
+            }
+
+            match (rule.operator, &rule.value, &rule.other) {
+                (Operator::Unique, None, None) => {}
+                (Operator::Unique, _, _) => {
+                    return Err(format!(
+                        "у правила `unique` для `{}` не должно быть value и other",
+                        rule.field
+                    ));
+                }
+                (_, Some(value), None) => {
+                    rule_value(&rule.field, value, today)
+                        .map_err(|err| format!("правило для `{}`: {err}", rule.field))?;
+                }
+                (_, None, Some(other)) if kind(other) != kind(&rule.field) => {
+                    return Err(format!(
+                        "поля `{}` и `{other}` разных типов, их нельзя сравнить",
+                        rule.field
+                    ));
+                }
+                (_, None, Some(_)) => {}
+                _ => {
+                    return Err(format!(
+                        "у правила для `{}` должно быть задано одно из value и other",
+                        rule.field
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Проверяет операции по правилам на текущую дату.
+    pub fn check(&self, records: &[CsvRecord]) -> Report {
+        self.check_at(records, today())
+    }
+
+    /// Проверяет операции по правилам, `today` в правилах заменяется на переданную дату.
+    pub fn check_at(&self, records: &[CsvRecord], today: NaiveDate) -> Report {
+        let mut violations = Vec::new();
+        for rule in &self.rules {
+            let applies = |record: &CsvRecord| {
+                rule.when
+                    .iter()
+                    .all(|(field, value)| record.field(field).as_ref() == Some(value))
+            };
+
+            if rule.operator == Operator::Unique {
+                let mut seen: HashMap<String, usize> = HashMap::new();
+                for (index, record) in records.iter().enumerate().filter(|(_, r)| applies(r)) {
+                    let value = record.field(&rule.field).unwrap_or_default();
+                    if let Some(first) = seen.get(&value) {
+                        violations.push(rule.violation(
+                            index,
+                            record,
+                            format!(
+                                "значение `{value}` уже встречалось в операции №{}",
+                                first + 1
+                            ),
+                        ));
+                    } else {
+                        seen.insert(value, index);
+                    }
+                }
+                continue;
+            }
+
+            for (index, record) in records.iter().enumerate().filter(|(_, r)| applies(r)) {
+                if let Err(message) = rule.check_record(record, today) {
+                    violations.push(rule.violation(index, record, message));
+                }
+            }
+        }
+
+        violations.sort_by_key(|v| v.index);
+        Report { violations }
+    }
+}
+
+impl Rule {
+    /// Описание условия правила, например `amount > 0`.
+    pub fn description(&self) -> String {
+        let operand = match (&self.value, &self.other) {
+            (Some(RuleValue::Number(value)), _) => value.to_string(),
+            (Some(RuleValue::Text(value)), _) => format!("\"{value}\""),
+            (None, Some(other)) => other.clone(),
+            (None, None) => String::new(),
+        };
+        format!("{} {} {operand}", self.field, self.operator)
+            .trim_end()
+            .to_string()
+    }
+
+    fn check_record(&self, record: &CsvRecord, today: NaiveDate) -> Result<(), String> {
+        let actual = record_value(record, &self.field)?;
+        let expected = match (&self.value, &self.other) {
+            (Some(value), _) => rule_value(&self.field, value, today)?,
+            (None, Some(other)) => record_value(record, other)?,
+            (None, None) => unreachable!("правила проверяются при загрузке"),
+        };
+
+        let ordering = actual
+            .partial_cmp(&expected)
+            .ok_or_else(|| format!("значения `{actual}` и `{expected}` нельзя сравнить"))?;
+        let satisfied = match self.operator {
+            Operator::Eq => ordering == Ordering::Equal,
+            Operator::Ne => ordering != Ordering::Equal,
+            Operator::Gt => ordering == Ordering::Greater,
+            Operator::Ge => ordering != Ordering::Less,
+            Operator::Lt => ordering == Ordering::Less,
+            Operator::Le => ordering != Ordering::Greater,
+            Operator::Unique => true,
+        };
+
+        if satisfied {
+            Ok(())
+        } else {
+            Err(format!(
+                "нарушено условие `{}`: {} = `{actual}`",
+                self.description(),
+                self.field
+            ))
+        }
+    }
+
+    fn violation(&self, index: usize, record: &CsvRecord, message: String) -> Violation {
+        Violation {
+            rule: self.name.clone().unwrap_or_else(|| self.description()),
+            severity: self.severity,
+            index,
+            transaction: record.transaction.clone(),
+            message,
+        }
+    }
+}
+
+impl Report {
+    /// Нарушения правил с важностью [Severity::Error].
+    pub fn errors(&self) -> impl Iterator<Item = &Violation> {
+        self.violations
+            .iter()
+            .filter(|v| v.severity == Severity::Error)
+    }
+
+    /// Есть ли нарушения правил с важностью [Severity::Error].
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+}
+
+fn rule_value(field: &str, value: &RuleValue, today: NaiveDate) -> Result<Value, String> {
+    match (field, value) {
+        ("amount", RuleValue::Number(value)) => Ok(Value::Number(*value)),
+        ("amount", RuleValue::Text(value)) => Err(format!("ожидалось число, получено `{value}`")),
+        ("date", RuleValue::Text(value)) if value == "today" => Ok(Value::Date(today)),
+        ("date", RuleValue::Text(value)) => parse_date(value)
+            .map(Value::Date)
+            .map_err(|err| format!("некорректная дата `{value}`: {err}")),
+        ("date", RuleValue::Number(value)) => Err(format!("ожидалась дата, получено `{value}`")),
+        (_, RuleValue::Text(value)) => Ok(Value::Text(value.clone())),
+        (_, RuleValue::Number(value)) => Ok(Value::Text(value.to_string())),
+    }
+}
+
+/// Текущая дата по UTC.
+fn today() -> NaiveDate {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() / 86_400)
+        .unwrap_or_default();
+    NaiveDate::from_ymd_opt(1970, 1, 1)
+        .and_then(|epoch| epoch.checked_add_days(Days::new(days)))
+        .unwrap_or_default()
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use Operator::*;
+        match self {
+            Eq => write!(f, "=="),
+            Ne => write!(f, "!="),
+            Gt => write!(f, ">"),
+            Ge => write!(f, ">="),
+            Lt => write!(f, "<"),
+            Le => write!(f, "<="),
+            Unique => write!(f, "unique"),
+        }
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "ошибка"),
+            Severity::Warning => write!(f, "предупреждение"),
+        }
+    }
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: операция `{}` (№{}), правило `{}`: {}",
+            self.severity,
+            self.transaction,
+            self.index + 1,
+            self.rule,
+            self.message
+        )
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for violation in &self.violations {
+            writeln!(f, "{violation}")?;
+        }
+        let errors = self.errors().count();
+        write!(
+            f,
+            "ошибок: {errors}, предупреждений: {}",
+            self.violations.len() - errors
+        )
+    }
+}
@@ -0,0 +1,101 @@
+//! Модуль сортирует операции по одному или нескольким полям, например
+//! по дате, а внутри одной даты по убыванию суммы: `date,-amount`.
+//!
+//! Суммы сравниваются как числа, даты как даты, остальные поля как строки.
+//! Сортировка устойчивая: операции с одинаковыми ключами остаются в исходном порядке.
+
+use crate::records::{CsvRecord, FIELDS};
+use crate::value::{Value, record_value};
+
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+/// Поле, по которому сортируются операции.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortKey {
+    /// Поле из [FIELDS].
+    pub field: String,
+    /// Сортировать по убыванию.
+    pub descending: bool,
+}
+
+impl SortKey {
+    /// Сортировка по возрастанию.
+    pub fn asc(field: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            descending: false,
+        }
+    }
+
+    /// Сортировка по убыванию.
+    pub fn desc(field: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            descending: true,
+        }
+    }
+
+    /// Разбирает ключи через запятую, `-` перед полем означает сортировку по убыванию.
+    pub fn parse_list(keys: &str) -> Result<Vec<Self>, String> {
+        keys.split(',').map(|key| key.trim().parse()).collect()
+    }
+}
+
+/// Поле с необязательными `+` или `-` в начале, например `-amount`.
+impl FromStr for SortKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let key = match s.strip_prefix('-') {
+            Some(field) => SortKey::desc(field),
+            None => SortKey::asc(s.strip_prefix('+').unwrap_or(s)),
+        };
+        if !FIELDS.contains(&key.field.as_str()) {
+            return Err(format!("неизвестное поле `{}`", key.field));
+        }
+        Ok(key)
+    }
+}
+
+/// Сортирует операции по ключам: сначала по первому, при равенстве по второму и так далее.
+/// Ошибка, если ключей нет, поле неизвестно или у операции некорректная дата.
+pub fn sort(records: Vec<CsvRecord>, keys: &[SortKey]) -> Result<Vec<CsvRecord>, String> {
+    if keys.is_empty() {
+        return Err("нужно хотя бы одно поле для сортировки".into());
+    }
+    if let Some(key) = keys.iter().find(|k| !FIELDS.contains(&k.field.as_str())) {
+        return Err(format!("неизвестное поле `{}`", key.field));
+    }
+
+    let mut keyed: Vec<(Vec<Value>, CsvRecord)> = records
+        .into_iter()
+        .map(|record| {
+            let values = keys
+                .iter()
+                .map(|key| record_value(&record, &key.field))
+                .collect::<Result<_, _>>()
+                .map_err(|err| format!("операция `{}`: {err}", record.transaction))?;
+            Ok((values, record))
+        })
+        .collect::<Result<_, String>>()?;
+
+    keyed.sort_by(|(a, _), (b, _)| compare(a, b, keys));
+    Ok(keyed.into_iter().map(|(_, record)| record).collect())
+}
+
+fn compare(a: &[Value], b: &[Value], keys: &[SortKey]) -> Ordering {
+    a.iter()
+        .zip(b)
+        .zip(keys)
+        .map(|((a, b), key)| {
+            let ordering = a.partial_cmp(b).unwrap_or(Ordering::Equal);
+            if key.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
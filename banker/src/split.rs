@@ -0,0 +1,219 @@
+//! Модуль раскладывает операции по нескольким файлам, например каждому банку
+//! получателя свой файл за каждый месяц: `out/{to_bank}/{date:%Y-%m}.csv`.
+//!
+//! Путь файла задаётся шаблоном [Template]: в фигурных скобках пишется поле операции,
+//! у даты через `:` можно указать формат strftime, а `{part}` подставляет номер
+//! части, если операций в файле больше, чем разрешено.
+
+use crate::records::{CsvRecord, FIELDS, parse_date};
+
+use chrono::format::{Item, StrftimeItems};
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Подставляет номер части файла, начиная с 1.
+pub const PART: &str = "part";
+
+/// Шаблон пути файла, например `out/{to_bank}/{date:%Y-%m}.csv`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    pieces: Vec<Piece>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Piece {
+    Text(String),
+    Field(String),
+    Date(String),
+    Part,
+}
+
+/// Операции, которые записываются в один файл.
+#[derive(Debug)]
+pub struct Output {
+    pub path: PathBuf,
+    pub records: Vec<CsvRecord>,
+}
+
+impl Template {
+    /// Есть ли в шаблоне номер части `{part}`.
+    pub fn has_part(&self) -> bool {
+        self.pieces.contains(&Piece::Part)
+    }
+
+    /// Путь файла для операции и номера части.
+    ///
+    /// Значения полей со слешами не создают лишних каталогов: `/` и `\` заменяются на `_`,
+    /// пустое значение, `.` и `..` тоже записываются как `_`. Ошибка, если значение
+    /// начинается с точки или содержит нулевой символ.
+    pub fn render(&self, record: &CsvRecord, part: usize) -> Result<String, String> {
+        let mut path = String::new();
+        for piece in &self.pieces {
+            match piece {
+                Piece::Text(text) => path.push_str(text),
+                Piece::Field(field) => {
+                    let value = record.field(field).unwrap_or_default();
+                    let segment = path_segment(&value)
+                        .map_err(|err| format!("операция `{}`: {err}", record.transaction))?;
+                    path.push_str(&segment);
+                }
+                Piece::Date(format) => {
+                    let date = parse_date(&record.date).map_err(|err| {
+                        format!(
+                            "операция `{}`: некорректная дата: {err}",
+                            record.transaction
+                        )
+                    })?;
+                    let segment = path_segment(&date.format(format).to_string())
+                        .map_err(|err| format!("операция `{}`: {err}", record.transaction))?;
+                    path.push_str(&segment);
+                }
+                Piece::Part => path.push_str(&part.to_string()),
+            }
+        }
+        Ok(path)
+    }
+}
+
+/// Разбирает шаблон: текст и поля в фигурных скобках.
+/// Ошибка, если скобка не закрыта, поле неизвестно или формат задан не у даты.
+impl FromStr for Template {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut pieces = Vec::new();
+        let mut rest = s;
+        while let Some(start) = rest.find('{') {
+            if start > 0 {
+                pieces.push(Piece::Text(rest[..start].into()));
+            }
+            let end = rest[start..]
+                .find('}')
+                .map(|end| start + end)
+                .ok_or_else(|| format!("не закрыта скобка в `{}`", &rest[start..]))?;
+            pieces.push(placeholder(&rest[start + 1..end])?);
+            rest = &rest[end + 1..];
+        }
+        if rest.contains('}') {
+            return Err(format!("лишняя скобка в `{rest}`"));
+        }
+        if !rest.is_empty() {
+            pieces.push(Piece::Text(rest.into()));
+        }
+        Ok(Self { pieces })
+    }
+}
+
+impl Display for Template {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for piece in &self.pieces {
+            match piece {
+                Piece::Text(text) => write!(f, "{text}")?,
+                Piece::Field(field) => write!(f, "{{{field}}}")?,
+                Piece::Date(format) => write!(f, "{{date:{format}}}")?,
+                Piece::Part => write!(f, "{{{PART}}}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn placeholder(name: &str) -> Result<Piece, String> {
+    let (field, format) = match name.split_once(':') {
+        Some((field, format)) => (field.trim(), Some(format)),
+        None => (name.trim(), None),
+    };
+
+    match (field, format) {
+        (PART, None) => Ok(Piece::Part),
+        ("date", Some(format)) => {
+            if StrftimeItems::new(format).any(|item| item == Item::Error) {
+                return Err(format!("некорректный формат даты `{format}`"));
+            }
+            Ok(Piece::Date(format.into()))
+        }
+        (field, Some(_)) if FIELDS.contains(&field) => Err(format!(
+            "формат можно задать только для `date`, а не для `{field}`"
+        )),
+        (field, _) if FIELDS.contains(&field) => Ok(Piece::Field(field.into())),
+        (field, _) => Err(format!(
+            "неизвестное поле `{field}`, доступны: {}, {PART}",
+            FIELDS.join(", ")
+        )),
+    }
+}
+
+/// Значение поля как часть пути. Операция не должна выводить файл за пределы каталога
+/// из шаблона, поэтому `.` и `..` тоже заменяются на `_`, а значения с точкой в начале
+/// и с нулевым символом отклоняются.
+fn path_segment(value: &str) -> Result<String, String> {
+    match value {
+        "" | "." | ".." => return Ok("_".into()),
+        _ if value.starts_with('.') => {
+            return Err(format!("значение `{value}` начинается с точки"));
+        }
+        _ if value.contains('\0') => {
+            return Err(format!(
+                "в значении `{}` нулевой символ",
+                value.escape_default()
+            ));
+        }
+        _ => {}
+    }
+    Ok(value.replace(['/', '\\'], "_"))
+}
+
+/// Раскладывает операции по файлам из шаблона, порядок операций в файле сохраняется,
+/// файлы идут в порядке первой операции.
+///
+/// С `max_records` в файл попадает не больше указанного числа операций, остальные
+/// уходят в следующие части, поэтому в шаблоне должен быть `{part}`.
+pub fn split(
+    records: Vec<CsvRecord>,
+    template: &Template,
+    max_records: Option<usize>,
+) -> Result<Vec<Output>, String> {
+    if let Some(max) = max_records {
+        if max == 0 {
+            return Err("в файле должна быть хотя бы одна операция".into());
+        }
+        if !template.has_part() {
+            return Err(format!(
+                "для ограничения числа операций в шаблоне нужен `{{{PART}}}`"
+            ));
+        }
+    }
+
+    // Ключ группы — путь для первой части, номер части подставляется позже.
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut groups: Vec<Vec<CsvRecord>> = Vec::new();
+    for record in records {
+        let key = template.render(&record, 1)?;
+        let position = *index.entry(key).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[position].push(record);
+    }
+
+    let mut outputs = Vec::new();
+    for group in groups {
+        let size = max_records.unwrap_or(group.len()).max(1);
+        let mut records = group.into_iter().peekable();
+        let mut part = 1;
+        while records.peek().is_some() {
+            let chunk: Vec<CsvRecord> = records.by_ref().take(size).collect();
+            let path = template.render(&chunk[0], part)?;
+            outputs.push(Output {
+                path: path.into(),
+                records: chunk,
+            });
+            part += 1;
+        }
+    }
+
+    Ok(outputs)
+}
@@ -0,0 +1,93 @@
+//! Модуль описывает банковскую выписку [Statement]: операции по счёту за период
+//! вместе с входящим и исходящим остатками, как в MT940, CAMT.053 и BAI2.
+
+use crate::records::{CsvRecord, CsvRecords, format_amount, to_cents};
+
+use chrono::NaiveDate;
+
+use std::error::Error;
+use std::fmt::Display;
+
+/// Выписка по счёту за период.
+///
+/// Суммы операций в выписке берутся со знаком: поступления на счёт
+/// положительные, списания отрицательные.
+#[derive(Debug)]
+pub struct Statement {
+    /// Идентификатор счёта, например IBAN или номер счёта.
+    pub account: String,
+    /// Период выписки.
+    pub period: Period,
+    /// Входящий остаток на начало периода.
+    pub opening_balance: f64,
+    /// Исходящий остаток на конец периода.
+    pub closing_balance: f64,
+    /// Операции по счёту за период.
+    pub entries: Vec<CsvRecord>,
+}
+
+/// Период выписки, обе границы включаются.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Period {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+/// Расхождение остатков выписки: входящий остаток плюс операции не равен исходящему.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BalanceMismatch {
+    /// Исходящий остаток, который получается из входящего и операций.
+    pub expected: f64,
+    /// Исходящий остаток, указанный в выписке.
+    pub actual: f64,
+    /// Разница `actual - expected`.
+    pub discrepancy: f64,
+}
+
+impl Statement {
+    /// Сумма операций выписки.
+    pub fn total(&self) -> f64 {
+        self.total_cents() as f64 / 100.0
+    }
+
+    /// Проверяет, что входящий остаток плюс сумма операций равен исходящему.
+    /// Суммы сравниваются с точностью до копейки.
+    pub fn check_balance(&self) -> Result<(), BalanceMismatch> {
+        let expected = to_cents(self.opening_balance) + self.total_cents();
+        let actual = to_cents(self.closing_balance);
+        if expected == actual {
+            return Ok(());
+        }
+
+        Err(BalanceMismatch {
+            expected: expected as f64 / 100.0,
+            actual: actual as f64 / 100.0,
+            discrepancy: (actual - expected) as f64 / 100.0,
+        })
+    }
+
+    fn total_cents(&self) -> i64 {
+        self.entries.iter().map(|r| to_cents(r.amount)).sum()
+    }
+}
+
+/// Операции выписки можно записать в любом формате через [CsvRecords].
+impl From<Statement> for CsvRecords {
+    fn from(value: Statement) -> Self {
+        value.entries.into()
+    }
+}
+
+impl Error for BalanceMismatch {}
+
+impl Display for BalanceMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "остатки не сходятся: ожидался исходящий остаток {}, в выписке {}, расхождение {}",
+            format_amount(self.expected),
+            format_amount(self.actual),
+            format_amount(self.discrepancy)
+        )
+    }
+}
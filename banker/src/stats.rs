@@ -0,0 +1,186 @@
+//! Модуль считает итоги по операциям: количество, сумму, минимум, максимум
+//! и среднее по `amount` в группах операций с одинаковыми значениями полей,
+//! например по отправителю, по паре банков или по дню.
+//!
+//! Суммы складываются в копейках, поэтому итоги не накапливают ошибку округления `f64`.
+
+use crate::printers::stats_printer;
+use crate::records::{CsvRecord, FIELDS, to_cents};
+use crate::value::{Value, record_value};
+
+use serde::{Serialize, Serializer};
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::{self, Write};
+
+/// Сумма в копейках.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Amount(i64);
+
+/// Итоги по группе операций.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Group {
+    /// Значения полей группировки в порядке [Summary::group_by].
+    pub key: Vec<String>,
+    pub count: usize,
+    pub sum: Amount,
+    pub min: Amount,
+    pub max: Amount,
+    /// Среднее, округлённое до копейки.
+    pub avg: Amount,
+}
+
+/// Итоги по всем группам.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Summary {
+    /// Поля из [FIELDS], по которым сгруппированы операции.
+    pub group_by: Vec<String>,
+    /// Группы, упорядоченные по значениям полей: суммы как числа, даты как даты.
+    pub groups: Vec<Group>,
+}
+
+impl Amount {
+    pub fn from_cents(cents: i64) -> Self {
+        Self(cents)
+    }
+
+    pub fn cents(self) -> i64 {
+        self.0
+    }
+
+    /// Сумма в валюте операций, для записи в форматы без десятичного типа.
+    pub fn as_f64(self) -> f64 {
+        self.0 as f64 / 100.0
+    }
+}
+
+/// Сумма округляется до копейки.
+impl From<f64> for Amount {
+    fn from(amount: f64) -> Self {
+        Self(to_cents(amount))
+    }
+}
+
+/// Группирует операции по полям и считает итоги по `amount`.
+/// Без полей группировки возвращает одну группу с итогами по всем операциям,
+/// а если операций нет — ни одной группы: у пустой группы нет минимума и среднего.
+pub fn aggregate(records: &[CsvRecord], group_by: &[String]) -> Result<Summary, String> {
+    if let Some(field) = group_by.iter().find(|f| !FIELDS.contains(&f.as_str())) {
+        return Err(format!("неизвестное поле `{field}`"));
+    }
+
+    let mut index: HashMap<Vec<String>, usize> = HashMap::new();
+    let mut groups: Vec<(Vec<Value>, Totals)> = Vec::new();
+    for record in records {
+        let key: Vec<String> = group_by
+            .iter()
+            .map(|field| record.field(field).unwrap_or_default())
+            .collect();
+        let position = match index.get(&key) {
+            Some(&position) => position,
+            None => {
+                let values = group_by
+                    .iter()
+                    .map(|field| record_value(record, field))
+                    .collect::<Result<_, _>>()
+                    .map_err(|err| format!("операция `{}`: {err}", record.transaction))?;
+                groups.push((values, Totals::new(key.clone())));
+                index.insert(key, groups.len() - 1);
+                groups.len() - 1
+            }
+        };
+        groups[position].1.add(to_cents(record.amount));
+    }
+
+    groups.sort_by(|(a, _), (b, _)| {
+        a.iter()
+            .zip(b)
+            .map(|(a, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+
+    Ok(Summary {
+        group_by: group_by.to_vec(),
+        groups: groups
+            .into_iter()
+            .map(|(_, totals)| totals.into_group())
+            .collect(),
+    })
+}
+
+impl Summary {
+    /// Записывает итоги текстовой таблицей с выровненными колонками.
+    pub fn print_table(&self, writer: impl Write) -> Result<(), io::Error> {
+        stats_printer::print_table(writer, self)
+    }
+
+    /// Записывает итоги в `csv`: поля группировки, затем `count`, `sum`, `min`, `max`, `avg`.
+    pub fn print_csv(&self, writer: impl Write) -> Result<(), io::Error> {
+        stats_printer::print_csv(writer, self)
+    }
+
+    /// Записывает итоги объектом `json` с полями `group_by` и `groups`, как в [Summary].
+    pub fn print_json(&self, writer: impl Write) -> Result<(), io::Error> {
+        stats_printer::print_json(writer, self)
+    }
+}
+
+/// Накопленные итоги группы.
+struct Totals {
+    key: Vec<String>,
+    count: usize,
+    sum: i64,
+    min: i64,
+    max: i64,
+}
+
+impl Totals {
+    fn new(key: Vec<String>) -> Self {
+        Self {
+            key,
+            count: 0,
+            sum: 0,
+            min: i64::MAX,
+            max: i64::MIN,
+        }
+    }
+
+    fn add(&mut self, cents: i64) {
+        self.count += 1;
+        self.sum += cents;
+        self.min = self.min.min(cents);
+        self.max = self.max.max(cents);
+    }
+
+    fn into_group(self) -> Group {
+        // Округление половины копейки от нуля.
+        let count = self.count as i64;
+        let avg = (2 * self.sum + self.sum.signum() * count) / (2 * count);
+        Group {
+            key: self.key,
+            count: self.count,
+            sum: Amount(self.sum),
+            min: Amount(self.min),
+            max: Amount(self.max),
+            avg: Amount(avg),
+        }
+    }
+}
+
+impl Display for Amount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let cents = self.0.unsigned_abs();
+        write!(f, "{sign}{}.{:02}", cents / 100, cents % 100)
+    }
+}
+
+/// Записывается числом, например `515.55`.
+impl Serialize for Amount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.as_f64())
+    }
+}
@@ -1,6 +1,34 @@
+mod anonymize_tests;
+#[cfg(any(feature = "msgpack", feature = "cbor"))]
+mod binary_tests;
+mod categorize_tests;
+#[cfg(feature = "columnar")]
+mod columnar_tests;
 mod convert_tests;
+mod dedupe_tests;
+mod details_tests;
+mod diff_tests;
+mod direction_tests;
+mod filter_tests;
+mod fixed_tests;
+mod fx_tests;
+mod generate_tests;
+mod journal_tests;
+mod merge_tests;
 mod parse_tests;
 mod print_tests;
+mod reconcile_tests;
+mod report_tests;
+mod rules_tests;
+mod sort_tests;
+mod split_tests;
+#[cfg(feature = "sqlite")]
+mod sqlite_tests;
+mod statement_tests;
+mod stats_tests;
+mod validation_tests;
+mod xml_tests;
+mod yaml_toml_tests;
 
 use crate::{CsvRecord, JsonRecord};
 use std::io::Cursor;
@@ -16,6 +44,10 @@ fn sample_json_record() -> JsonRecord {
         transaction_id: "123".into(),
         quantity: 500.05,
         date: "24-01-2025".into(),
+        description: None,
+        end_to_end_id: None,
+        remittance_reference: None,
+        value_date: None,
     }
 }
 
@@ -35,14 +67,24 @@ fn sample_json_data() -> Cursor<String> {
 }
 
 fn sample_csv_record() -> CsvRecord {
+    CsvRecord::new(
+        "Alice",
+        "bank_a",
+        "Bob",
+        "bank_b",
+        "123",
+        500.05,
+        "24-01-2025",
+    )
+}
+
+fn detailed_csv_record() -> CsvRecord {
     CsvRecord {
-        from_client: "Alice".into(),
-        from_bank: "bank_a".into(),
-        to_client: "Bob".into(),
-        to_bank: "bank_b".into(),
-        transaction: "123".into(),
-        amount: 500.05,
-        date: "24-01-2025".into(),
+        description: Some("Оплата по счёту 17".into()),
+        end_to_end_id: Some("E2E-0001".into()),
+        remittance_reference: Some("INV-17".into()),
+        value_date: Some("27-01-2025".into()),
+        ..sample_csv_record()
     }
 }
 
@@ -0,0 +1,159 @@
+use super::*;
+use crate::anonymize::{self, AnonymizeOptions, Anonymizer};
+use crate::fx::{self, RateTable};
+use crate::records::{Source, parse_date};
+
+fn anonymizer(options: AnonymizeOptions) -> Anonymizer {
+    Anonymizer::new("secret", options).unwrap()
+}
+
+#[test]
+fn same_name_gets_same_pseudonym() {
+    let mut second = sample_csv_record();
+    second.from_client = "Bob".into();
+    second.to_client = "Alice".into();
+
+    let records = anonymizer(AnonymizeOptions::default())
+        .apply(vec![sample_csv_record(), second])
+        .unwrap();
+
+    assert_eq!(records[0].from_client, records[1].to_client);
+    assert_eq!(records[0].to_client, records[1].from_client);
+    assert_ne!(records[0].from_client, records[0].to_client);
+    assert!(records[0].from_client.starts_with("client_"));
+    assert_eq!(records[0].amount, 500.05);
+    assert_eq!(records[0].date, "24-01-2025");
+}
+
+#[test]
+fn pseudonym_depends_on_key() {
+    let other = Anonymizer::new("other", AnonymizeOptions::default()).unwrap();
+
+    assert_ne!(
+        anonymizer(AnonymizeOptions::default()).pseudonym("Alice"),
+        other.pseudonym("Alice")
+    );
+}
+
+#[test]
+fn accounts_keep_last_four_characters() {
+    assert_eq!(
+        anonymize::mask_account("044525225/40702810900000000001"),
+        "044525225/****************0001"
+    );
+    assert_eq!(
+        anonymize::mask_account("DE89370400440532013000"),
+        "******************3000"
+    );
+    assert_eq!(anonymize::mask_account("DEUTDEFF"), "DEUTDEFF");
+    assert_eq!(anonymize::mask_account("bank_a"), "bank_a");
+}
+
+#[test]
+fn bare_account_numbers_are_masked() {
+    assert_eq!(
+        anonymize::mask_account("40702810900000000001"),
+        "****************0001"
+    );
+    assert_eq!(
+        anonymize::mask_account("Сбербанк, счёт 40817810099910004312"),
+        "Сбербанк, счёт ****************4312"
+    );
+    assert_eq!(anonymize::mask_account("bank 1234"), "bank 1234");
+}
+
+#[test]
+fn jitter_stays_within_bounds_and_is_repeatable() {
+    let options = AnonymizeOptions {
+        amount_jitter: 0.1,
+        date_jitter_days: 3,
+    };
+    let first = anonymizer(options)
+        .apply(vec![sample_csv_record()])
+        .unwrap();
+    let second = anonymizer(options)
+        .apply(vec![sample_csv_record()])
+        .unwrap();
+
+    assert_eq!(first, second);
+    let record = &first[0];
+    assert!(
+        (450.04..=550.06).contains(&record.amount),
+        "{}",
+        record.amount
+    );
+    let shift = crate::records::parse_date(&record.date).unwrap()
+        - crate::records::parse_date("24-01-2025").unwrap();
+    assert!(shift.num_days().abs() <= 3, "{}", record.date);
+}
+
+#[test]
+fn details_do_not_leak() {
+    let options = AnonymizeOptions {
+        amount_jitter: 0.0,
+        date_jitter_days: 5,
+    };
+    let mut record = sample_csv_record();
+    record.description = Some("Оплата Alice по договору 12".into());
+    record.end_to_end_id = Some("E2E-0001".into());
+    record.value_date = Some("26-01-2025".into());
+
+    let record = &anonymizer(options).apply(vec![record]).unwrap()[0];
+
+    assert_eq!(record.description, None);
+    assert!(record.end_to_end_id.as_ref().unwrap().starts_with("ref_"));
+    let date = crate::records::parse_date(&record.date).unwrap();
+    let value_date = crate::records::parse_date(record.value_date.as_ref().unwrap()).unwrap();
+    assert_eq!((value_date - date).num_days(), 2);
+}
+
+#[test]
+fn empty_key_is_rejected() {
+    let err = Anonymizer::new("", AnonymizeOptions::default()).unwrap_err();
+
+    assert_eq!(err, "ключ не может быть пустым");
+}
+
+#[test]
+fn huge_date_jitter_is_rejected() {
+    let options = AnonymizeOptions {
+        amount_jitter: 0.0,
+        date_jitter_days: u64::MAX,
+    };
+
+    assert!(Anonymizer::new("secret", options).is_err());
+}
+
+#[test]
+fn pseudonym_uses_eight_bytes() {
+    let pseudonym = anonymizer(AnonymizeOptions::default()).pseudonym("Alice");
+
+    assert_eq!(pseudonym.len(), "client_".len() + 16);
+}
+
+#[test]
+fn converted_amounts_and_source_do_not_leak() {
+    let mut rates = RateTable::default();
+    rates.insert("USD", "RUB", parse_date("23-01-2025").unwrap(), 100.0);
+    let mut record = sample_csv_record();
+    record.source = Some(Source {
+        file: "/home/alice/statements/january.csv".into(),
+        row: 1,
+    });
+    let records = fx::convert(vec![record], &rates, "USD", "RUB", 7).unwrap();
+    let options = AnonymizeOptions {
+        amount_jitter: 0.2,
+        date_jitter_days: 5,
+    };
+
+    let record = &anonymizer(options).apply(records).unwrap()[0];
+
+    let conversion = record.conversion.as_ref().unwrap();
+    assert_ne!(record.amount, 50005.0);
+    assert_ne!(conversion.original_amount, 500.05);
+    assert!((record.amount / conversion.original_amount - 100.0).abs() < 0.01);
+    let shift = parse_date(&record.date).unwrap() - parse_date("24-01-2025").unwrap();
+    let rate_shift = parse_date(&conversion.rate_date).unwrap() - parse_date("23-01-2025").unwrap();
+    assert_eq!(shift, rate_shift);
+    assert_eq!(record.source, None);
+}
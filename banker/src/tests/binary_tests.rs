@@ -0,0 +1,104 @@
+use super::*;
+use crate::{CsvRecords, convert_to, parse, print};
+
+#[cfg(feature = "msgpack")]
+#[test]
+fn msgpack_records_survive_round_trip() {
+    use crate::records::MsgpackRecords;
+
+    let records: MsgpackRecords = vec![sample_csv_record(), sample_csv_record()].into();
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let parsed: MsgpackRecords = parse(Cursor::new(buffer)).unwrap();
+    let csv_records: CsvRecords = convert_to(parsed);
+
+    assert_eq!(csv_records.list().len(), 2);
+    assert_eq!(csv_records.list().first().unwrap(), &sample_csv_record());
+}
+
+#[cfg(feature = "msgpack")]
+#[test]
+fn msgpack_details_survive_round_trip() {
+    use crate::records::MsgpackRecords;
+
+    let records: MsgpackRecords = vec![detailed_csv_record(), sample_csv_record()].into();
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    // Вторая операция без необязательных полей остаётся картой из 7 пар.
+    let maps: Vec<std::collections::HashMap<String, serde::de::IgnoredAny>> =
+        rmp_serde::from_slice(&buffer).unwrap();
+    assert_eq!(maps[0].len(), 11);
+    assert_eq!(maps[1].len(), 7);
+
+    let parsed: MsgpackRecords = parse(Cursor::new(buffer)).unwrap();
+    assert_eq!(
+        parsed.list(),
+        &vec![detailed_csv_record(), sample_csv_record()]
+    );
+}
+
+#[cfg(feature = "msgpack")]
+#[test]
+fn msgpack_records_are_maps_with_field_names() {
+    use crate::records::MsgpackRecords;
+
+    let records: MsgpackRecords = vec![sample_csv_record()].into();
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    // fixarray из одного элемента, затем fixmap из 7 пар, первый ключ `from_client`.
+    assert_eq!(&buffer[..3], &[0x91, 0x87, 0xab]);
+    assert_eq!(&buffer[3..14], b"from_client");
+}
+
+#[cfg(feature = "cbor")]
+#[test]
+fn cbor_records_survive_round_trip() {
+    use crate::records::CborRecords;
+
+    let records: CborRecords = vec![sample_csv_record(), sample_csv_record()].into();
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let parsed: CborRecords = parse(Cursor::new(buffer)).unwrap();
+    let csv_records: CsvRecords = convert_to(parsed);
+
+    assert_eq!(csv_records.list().len(), 2);
+    assert_eq!(csv_records.list().first().unwrap(), &sample_csv_record());
+}
+
+#[cfg(feature = "cbor")]
+#[test]
+fn cbor_details_survive_round_trip() {
+    use crate::records::CborRecords;
+
+    let records: CborRecords = vec![detailed_csv_record(), sample_csv_record()].into();
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let parsed: CborRecords = parse(Cursor::new(buffer)).unwrap();
+    assert_eq!(
+        parsed.list(),
+        &vec![detailed_csv_record(), sample_csv_record()]
+    );
+}
+
+#[cfg(feature = "cbor")]
+#[test]
+fn cbor_parse_fails_on_missing_field() {
+    use crate::records::CborRecords;
+
+    let mut buffer = Vec::new();
+    ciborium::into_writer(&json!([{ "from_client": "Alice" }]), &mut buffer).unwrap();
+
+    let err = parse::<CborRecords>(Cursor::new(buffer)).err().unwrap();
+
+    assert!(err.to_string().contains("from_bank"), "{err}");
+}
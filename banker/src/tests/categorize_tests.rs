@@ -0,0 +1,108 @@
+use super::*;
+use crate::CsvRecords;
+use crate::categorize::{self, Categories};
+
+const RULES: &str = r#"
+[[category]]
+name = "internal"
+counterparty = "^ООО Ромашка$"
+bank = "bank_a"
+
+[[category]]
+name = "salary"
+counterparty = "(?i)salary"
+min_amount = 1000
+
+[[category]]
+name = "small"
+max_amount = 100
+"#;
+
+fn record(to_client: &str, to_bank: &str, amount: f64) -> CsvRecord {
+    let mut record = sample_csv_record();
+    record.to_client = to_client.into();
+    record.to_bank = to_bank.into();
+    record.amount = amount;
+    record
+}
+
+#[test]
+fn first_matching_rule_wins() {
+    let categories = Categories::from_toml(RULES).unwrap();
+
+    let records = categories.apply(vec![
+        record("ООО Ромашка", "bank_b", 50.0),
+        record("Bob SALARY", "bank_b", 5000.0),
+        record("Bob salary", "bank_b", 500.0),
+        record("Carol", "bank_c", 80.0),
+    ]);
+
+    let categories: Vec<Option<&str>> = records.iter().map(|r| r.category.as_deref()).collect();
+    assert_eq!(
+        categories,
+        vec![Some("internal"), Some("salary"), None, Some("small")]
+    );
+    assert_eq!(categorize::uncategorized(&records), vec![2]);
+}
+
+#[test]
+fn category_is_written_to_csv() {
+    let categories = Categories::from_toml(RULES).unwrap();
+    let records: CsvRecords = categories
+        .apply(vec![sample_csv_record(), record("Carol", "bank_c", 80.0)])
+        .into();
+
+    let mut buffer = Vec::new();
+    crate::print(&mut buffer, &records).unwrap();
+
+    let csv = String::from_utf8(buffer).unwrap();
+    assert_eq!(
+        csv,
+        "from_client,from_bank,to_client,to_bank,transaction,amount,date,category\n\
+        Alice,bank_a,Bob,bank_b,123,500.05,24-01-2025,\n\
+        Alice,bank_a,Carol,bank_c,123,80.0,24-01-2025,small\n"
+    );
+}
+
+#[test]
+fn invalid_regex_is_rejected() {
+    let err =
+        Categories::from_toml("[[category]]\nname = \"x\"\ncounterparty = \"(\"\n").unwrap_err();
+
+    assert!(err.to_string().contains("некорректное выражение"), "{err}");
+}
+
+#[test]
+fn empty_amount_range_is_rejected() {
+    let err = Categories::from_toml(
+        "[[category]]\nname = \"rent\"\nmin_amount = 500\nmax_amount = 100\n",
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "категория `rent`: min_amount 500 больше max_amount 100"
+    );
+}
+
+#[test]
+fn rule_matches_payment_description() {
+    let categories = Categories::from_toml(
+        r#"
+        [[category]]
+        name = "rent"
+        description = "(?i)аренд"
+        "#,
+    )
+    .unwrap();
+    let mut rent = sample_csv_record();
+    rent.description = Some("Оплата аренды за январь".into());
+    let mut other = sample_csv_record();
+    other.description = Some("Оплата по счёту 17".into());
+
+    let records = categories.apply(vec![rent, other, sample_csv_record()]);
+
+    assert_eq!(records[0].category.as_deref(), Some("rent"));
+    assert_eq!(records[1].category, None);
+    assert_eq!(records[2].category, None);
+}
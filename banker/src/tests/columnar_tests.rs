@@ -0,0 +1,80 @@
+use super::*;
+use crate::records::{ArrowOptions, ArrowRecords, Compression, ParquetOptions, ParquetRecords};
+use crate::{CsvRecords, convert_to, parse, print};
+
+#[test]
+fn parquet_records_survive_round_trip() {
+    let records: ParquetRecords = vec![sample_csv_record(), sample_csv_record()].into();
+    let records = records.with_options(ParquetOptions {
+        row_group_size: 1,
+        compression: Compression::Snappy,
+    });
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let parsed: ParquetRecords = parse(Cursor::new(buffer)).unwrap();
+    let csv_records: CsvRecords = convert_to(parsed);
+
+    assert_eq!(csv_records.list().len(), 2);
+    assert_eq!(csv_records.list().first().unwrap(), &sample_csv_record());
+}
+
+#[test]
+fn arrow_records_survive_round_trip() {
+    let records: ArrowRecords = vec![sample_csv_record(), sample_csv_record()].into();
+    let records = records.with_options(ArrowOptions {
+        batch_size: 1,
+        compression: Compression::Lz4,
+    });
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let parsed: ArrowRecords = parse(Cursor::new(buffer)).unwrap();
+    let csv_records: CsvRecords = convert_to(parsed);
+
+    assert_eq!(csv_records.list().len(), 2);
+    assert_eq!(csv_records.list().first().unwrap(), &sample_csv_record());
+}
+
+#[test]
+fn arrow_print_fails_on_unsupported_compression() {
+    let records: ArrowRecords = vec![sample_csv_record()].into();
+    let records = records.with_options(ArrowOptions {
+        compression: Compression::Gzip,
+        ..Default::default()
+    });
+
+    let err = print(Vec::new(), &records).err().unwrap();
+
+    let expected = "не получилось сохранить результат: \
+        Invalid argument error: сжатие Gzip не поддерживается форматом arrow ipc";
+    assert_eq!(err.to_string(), expected);
+}
+
+#[test]
+fn parquet_print_fails_on_invalid_date() {
+    let mut record = sample_csv_record();
+    record.date = "2025/01/24".into();
+    let records: ParquetRecords = vec![record].into();
+
+    let err = print(Vec::new(), &records).err().unwrap();
+
+    assert!(err.to_string().contains("некорректная дата `2025/01/24`"));
+}
+
+#[test]
+fn columnar_details_survive_round_trip() {
+    let records = || vec![detailed_csv_record(), sample_csv_record()];
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &ParquetRecords::from(records())).unwrap();
+    let parsed: ParquetRecords = parse(Cursor::new(buffer)).unwrap();
+    assert_eq!(convert_to::<_, CsvRecords>(parsed).list(), &records());
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &ArrowRecords::from(records())).unwrap();
+    let parsed: ArrowRecords = parse(Cursor::new(buffer)).unwrap();
+    assert_eq!(convert_to::<_, CsvRecords>(parsed).list(), &records());
+}
@@ -0,0 +1,91 @@
+use super::*;
+use crate::dedupe::{self, DedupeOptions, Keep};
+
+fn sample_dedupe_records() -> Vec<CsvRecord> {
+    let mut resent = sample_csv_record();
+    resent.date = "26-01-2025".into();
+
+    let mut other = sample_csv_record();
+    other.transaction = "124".into();
+    other.date = "25-01-2025".into();
+
+    let mut late = sample_csv_record();
+    late.transaction = "125".into();
+    late.date = "10-02-2025".into();
+
+    vec![sample_csv_record(), other, resent, late]
+}
+
+#[test]
+fn duplicates_groups_records_by_transaction() {
+    let groups = dedupe::duplicates(&sample_dedupe_records(), &DedupeOptions::default()).unwrap();
+
+    assert_eq!(groups, vec![vec![0, 2]]);
+}
+
+#[test]
+fn duplicates_groups_same_amount_within_window() {
+    let options = DedupeOptions {
+        keys: vec!["amount".into(), "from_client".into(), "to_client".into()],
+        window_days: Some(1),
+    };
+
+    let groups = dedupe::duplicates(&sample_dedupe_records(), &options).unwrap();
+
+    assert_eq!(groups, vec![vec![0, 1]]);
+}
+
+#[test]
+fn window_is_measured_from_first_record_of_group() {
+    let records: Vec<CsvRecord> = ["01-01-2025", "04-01-2025", "07-01-2025", "10-01-2025"]
+        .into_iter()
+        .map(|date| {
+            let mut record = sample_csv_record();
+            record.date = date.into();
+            record
+        })
+        .collect();
+    let options = DedupeOptions {
+        keys: vec!["amount".into()],
+        window_days: Some(3),
+    };
+
+    let groups = dedupe::duplicates(&records, &options).unwrap();
+
+    assert_eq!(groups, vec![vec![0, 1], vec![2, 3]]);
+}
+
+#[test]
+fn dedupe_keeps_last_occurrence() {
+    let records = dedupe::dedupe(
+        sample_dedupe_records(),
+        &DedupeOptions::default(),
+        Keep::Last,
+    )
+    .unwrap();
+
+    let transactions: Vec<(&str, &str)> = records
+        .iter()
+        .map(|r| (r.transaction.as_str(), r.date.as_str()))
+        .collect();
+    assert_eq!(
+        transactions,
+        vec![
+            ("124", "25-01-2025"),
+            ("123", "26-01-2025"),
+            ("125", "10-02-2025")
+        ]
+    );
+}
+
+#[test]
+fn options_reject_date_key_with_window() {
+    let options = DedupeOptions {
+        keys: vec!["date".into()],
+        window_days: Some(3),
+    };
+
+    let err = dedupe::duplicates(&[], &options).unwrap_err();
+
+    assert_eq!(err, "с окном дат поле `date` не может быть ключом");
+}
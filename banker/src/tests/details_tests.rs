@@ -0,0 +1,55 @@
+use super::*;
+use crate::{CsvRecords, JsonRecords};
+use crate::{convert_to, parse, print};
+
+use std::io::Read;
+
+fn detailed_csv_data() -> Cursor<&'static str> {
+    Cursor::new(
+        "from_client,from_bank,to_client,to_bank,transaction,amount,date,description,value_date\n\
+        Alice,bank_a,Bob,bank_b,123,500.05,24-01-2025,Оплата по счёту 17,27-01-2025\n\
+        Bob,bank_b,Alice,bank_a,124,215.0,25-01-2025,,\n",
+    )
+}
+
+#[test]
+fn csv_details_survive_conversion_to_json() {
+    let records: CsvRecords = parse(detailed_csv_data()).unwrap();
+    let json_records: JsonRecords = convert_to(records);
+
+    let record = &json_records.list()[0];
+    assert_eq!(record.description.as_deref(), Some("Оплата по счёту 17"));
+    assert_eq!(record.value_date.as_deref(), Some("27-01-2025"));
+    assert_eq!(record.end_to_end_id, None);
+    assert_eq!(json_records.list()[1].description, None);
+}
+
+#[test]
+fn csv_prints_only_present_details() {
+    let records: CsvRecords = parse(detailed_csv_data()).unwrap();
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let mut expected = String::new();
+    detailed_csv_data().read_to_string(&mut expected).unwrap();
+    assert_eq!(String::from_utf8(buffer).unwrap(), expected);
+}
+
+#[test]
+fn json_omits_absent_details() {
+    let mut record = sample_csv_record();
+    record.end_to_end_id = Some("E2E-0001".into());
+    record.remittance_reference = Some("INV-17".into());
+    let records: JsonRecords = convert_to::<CsvRecords, JsonRecords>(vec![record].into());
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let json: serde_json::Value = serde_json::from_slice(&buffer).unwrap();
+    let object = json[0].as_object().unwrap();
+    assert_eq!(object["end_to_end_id"], "E2E-0001");
+    assert_eq!(object["remittance_reference"], "INV-17");
+    assert!(!object.contains_key("description"));
+    assert!(!object.contains_key("value_date"));
+}
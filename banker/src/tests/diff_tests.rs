@@ -0,0 +1,148 @@
+use super::*;
+use crate::diff::{self, Change};
+
+fn record(transaction: &str, amount: f64) -> CsvRecord {
+    let mut record = sample_csv_record();
+    record.transaction = transaction.into();
+    record.amount = amount;
+    record
+}
+
+fn transactions(records: &[CsvRecord]) -> Vec<&str> {
+    records.iter().map(|r| r.transaction.as_str()).collect()
+}
+
+fn key() -> Vec<String> {
+    vec!["transaction".into()]
+}
+
+#[test]
+fn diff_finds_added_removed_and_modified() {
+    let old = vec![record("1", 100.0), record("2", 200.0), record("3", 300.0)];
+    let mut changed = record("2", 250.0);
+    changed.to_client = "Carol".into();
+    let new = vec![record("4", 400.0), record("1", 100.0), changed];
+
+    let diff = diff::diff(old, new, &key()).unwrap();
+
+    assert_eq!(transactions(&diff.added), vec!["4"]);
+    assert_eq!(transactions(&diff.removed), vec!["3"]);
+    assert_eq!(diff.unchanged, 1);
+    assert_eq!(diff.modified.len(), 1);
+    assert_eq!(diff.modified[0].key, vec!["2"]);
+    assert_eq!(
+        diff.modified[0].changes,
+        vec![
+            Change {
+                field: "to_client".into(),
+                old: "Bob".into(),
+                new: "Carol".into(),
+            },
+            Change {
+                field: "amount".into(),
+                old: "200".into(),
+                new: "250".into(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn diff_pairs_repeated_keys_in_order() {
+    let old = vec![record("1", 100.0), record("1", 200.0)];
+    let new = vec![record("1", 100.0), record("1", 200.0), record("1", 300.0)];
+
+    let diff = diff::diff(old, new, &key()).unwrap();
+
+    assert_eq!(diff.unchanged, 2);
+    assert!(diff.modified.is_empty());
+    assert_eq!(diff.added.len(), 1);
+    assert_eq!(diff.added[0].amount, 300.0);
+}
+
+#[test]
+fn diff_ignores_amount_noise_below_cent() {
+    let diff = diff::diff(vec![record("1", 0.1 + 0.2)], vec![record("1", 0.3)], &key()).unwrap();
+
+    assert!(diff.is_empty());
+    assert_eq!(diff.unchanged, 1);
+}
+
+#[test]
+fn diff_rejects_unknown_key() {
+    let err = diff::diff(Vec::new(), Vec::new(), &["bank".into()]).unwrap_err();
+
+    assert_eq!(err, "неизвестное поле `bank`");
+}
+
+#[test]
+fn diff_prints_text_report() {
+    let old = vec![record("1", 100.0), record("2", 200.0)];
+    let new = vec![record("1", 150.0), record("3", 300.0)];
+    let diff = diff::diff(old, new, &key()).unwrap();
+
+    let mut buffer = Vec::new();
+    diff.print_text(&mut buffer).unwrap();
+
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "- transaction=2: from_client=Alice, from_bank=bank_a, to_client=Bob, to_bank=bank_b, \
+        amount=200, date=24-01-2025\n\
+        + transaction=3: from_client=Alice, from_bank=bank_a, to_client=Bob, to_bank=bank_b, \
+        amount=300, date=24-01-2025\n\
+        ~ transaction=1: amount: 100 -> 150\n\
+        Добавлено: 1, удалено: 1, изменено: 1, без изменений: 0\n"
+    );
+}
+
+#[test]
+fn diff_compares_details() {
+    let mut changed = detailed_csv_record();
+    changed.description = Some("Оплата по счёту 18".into());
+    changed.value_date = None;
+
+    let diff = diff::diff(vec![detailed_csv_record()], vec![changed], &key()).unwrap();
+
+    assert_eq!(
+        diff.modified[0].changes,
+        vec![
+            Change {
+                field: "description".into(),
+                old: "Оплата по счёту 17".into(),
+                new: "Оплата по счёту 18".into(),
+            },
+            Change {
+                field: "value_date".into(),
+                old: "27-01-2025".into(),
+                new: String::new(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn diff_prints_json_through_serialize() {
+    let mut changed = detailed_csv_record();
+    changed.amount = 600.0;
+    let old = vec![detailed_csv_record(), record("2", 200.0)];
+    let new = vec![changed, record("3", 300.0)];
+    let diff = diff::diff(old, new, &key()).unwrap();
+
+    let mut buffer = Vec::new();
+    diff.print_json(&mut buffer).unwrap();
+
+    let json: serde_json::Value = serde_json::from_slice(&buffer).unwrap();
+    assert_eq!(json["key"], serde_json::json!(["transaction"]));
+    assert_eq!(json["added"][0]["transaction"], "3");
+    assert_eq!(json["added"][0]["amount"], 300.0);
+    assert!(json["added"][0].get("description").is_none());
+    assert_eq!(json["removed"][0]["transaction"], "2");
+    assert_eq!(
+        json["modified"][0],
+        serde_json::json!({
+            "key": ["123"],
+            "changes": [{ "field": "amount", "old": "500.05", "new": "600" }],
+        })
+    );
+    assert_eq!(json["unchanged"], 0);
+}
@@ -0,0 +1,96 @@
+use super::*;
+use crate::direction::{Direction, Party, UnknownDirection};
+use crate::records::DebitCreditRecords;
+use crate::{parse, print};
+
+fn sample_account_records() -> Vec<CsvRecord> {
+    let mut incoming = sample_csv_record();
+    incoming.from_client = "Bob".into();
+    incoming.from_bank = "bank_b".into();
+    incoming.to_client = "Alice".into();
+    incoming.to_bank = "bank_a".into();
+    incoming.transaction = "124".into();
+    incoming.amount = 15.5;
+
+    vec![sample_csv_record(), incoming]
+}
+
+#[test]
+fn party_derives_direction_and_signed_amount() {
+    let records = sample_account_records();
+    let alice = Party::new("Alice");
+
+    assert_eq!(alice.direction(&records[0]), Some(Direction::Debit));
+    assert_eq!(alice.direction(&records[1]), Some(Direction::Credit));
+    assert_eq!(alice.signed_amount(&records[0]), Some(-500.05));
+    assert_eq!(alice.signed_amount(&records[1]), Some(15.5));
+    assert_eq!(
+        Party::new("Alice")
+            .with_bank("bank_b")
+            .direction(&records[0]),
+        None
+    );
+}
+
+#[test]
+fn sign_amounts_fails_on_unrelated_record() {
+    let err = Party::new("Carol")
+        .sign_amounts(sample_account_records())
+        .unwrap_err();
+
+    let expected = UnknownDirection {
+        transaction: "123".into(),
+    };
+    assert_eq!(err, expected);
+}
+
+#[test]
+fn debit_credit_records_survive_round_trip() {
+    let records =
+        DebitCreditRecords::from(sample_account_records()).with_party(Party::new("Alice"));
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let expected = "from_client,from_bank,to_client,to_bank,transaction,debit,credit,date\n\
+        Alice,bank_a,Bob,bank_b,123,500.05,,24-01-2025\n\
+        Bob,bank_b,Alice,bank_a,124,,15.5,24-01-2025\n";
+    assert_eq!(String::from_utf8(buffer.clone()).unwrap(), expected);
+
+    let parsed: DebitCreditRecords = parse(Cursor::new(buffer)).unwrap();
+    assert_eq!(parsed.list(), &sample_account_records());
+}
+
+#[test]
+fn debit_credit_parse_fails_when_both_columns_are_filled() {
+    let data = Cursor::new(
+        "from_client,from_bank,to_client,to_bank,transaction,debit,credit,date\n\
+        Alice,bank_a,Bob,bank_b,123,500.05,1,24-01-2025\n",
+    );
+
+    let err = parse::<DebitCreditRecords>(data).err().unwrap();
+
+    assert!(err.to_string().contains("ровно одна"), "{err}");
+}
+
+#[test]
+fn debit_credit_details_survive_round_trip() {
+    let mut records = sample_account_records();
+    records[0] = detailed_csv_record();
+    let debit_credit = DebitCreditRecords::from(records).with_party(Party::new("Alice"));
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &debit_credit).unwrap();
+
+    let header = "from_client,from_bank,to_client,to_bank,transaction,debit,credit,date,\
+        description,end_to_end_id,remittance_reference,value_date\n";
+    assert!(
+        String::from_utf8(buffer.clone())
+            .unwrap()
+            .starts_with(header)
+    );
+
+    let parsed: DebitCreditRecords = parse(Cursor::new(buffer)).unwrap();
+    assert_eq!(parsed.list()[0], detailed_csv_record());
+    assert_eq!(parsed.list()[1], sample_account_records()[1]);
+}
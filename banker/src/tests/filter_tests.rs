@@ -0,0 +1,114 @@
+use super::*;
+use crate::filter::Filter;
+
+fn sample_filter_records() -> Vec<CsvRecord> {
+    let mut large = sample_csv_record();
+    large.transaction = "124".into();
+    large.amount = 25000.0;
+    large.date = "15-02-2025".into();
+
+    let mut other_bank = sample_csv_record();
+    other_bank.transaction = "125".into();
+    other_bank.from_bank = "bank_c".into();
+    other_bank.amount = 30000.0;
+    other_bank.date = "20-02-2025".into();
+
+    vec![sample_csv_record(), large, other_bank]
+}
+
+fn transactions(records: &[CsvRecord]) -> Vec<&str> {
+    records.iter().map(|r| r.transaction.as_str()).collect()
+}
+
+#[test]
+fn filter_combines_number_text_and_date_conditions() {
+    let filter: Filter = r#"amount > 10000 && from_bank == "bank_a" && date >= 2025-02-01"#
+        .parse()
+        .unwrap();
+
+    let records = filter.apply(sample_filter_records()).unwrap();
+
+    assert_eq!(transactions(&records), vec!["124"]);
+}
+
+#[test]
+fn filter_respects_precedence_negation_and_parentheses() {
+    let or_and = Filter::parse(r#"amount < 1000 || amount > 10000 && from_bank == "bank_c""#)
+        .unwrap()
+        .apply(sample_filter_records())
+        .unwrap();
+    let grouped = Filter::parse(r#"!(amount < 1000 || from_bank == "bank_c")"#)
+        .unwrap()
+        .apply(sample_filter_records())
+        .unwrap();
+
+    assert_eq!(transactions(&or_and), vec!["123", "125"]);
+    assert_eq!(transactions(&grouped), vec!["124"]);
+}
+
+#[test]
+fn filter_rejects_unknown_field() {
+    let err = Filter::parse("amount > 0 && bank == \"bank_a\"").unwrap_err();
+
+    assert_eq!(err.position, Some(14));
+    assert!(err.message.contains("неизвестное поле `bank`"));
+    assert!(err.message.contains("from_bank"));
+}
+
+#[test]
+fn filter_rejects_type_mismatch() {
+    let text_amount = Filter::parse(r#"amount > "many""#).unwrap_err();
+    let number_date = Filter::parse("date < 100").unwrap_err();
+
+    assert_eq!(text_amount.position, Some(9));
+    assert!(text_amount.message.contains("поле `amount` число"));
+    assert!(number_date.message.contains("поле `date` дата"));
+}
+
+#[test]
+fn filter_reports_syntax_errors() {
+    assert!(
+        Filter::parse("amount >")
+            .unwrap_err()
+            .message
+            .contains("оборвалось")
+    );
+    assert!(
+        Filter::parse("(amount > 1")
+            .unwrap_err()
+            .message
+            .contains("`)`")
+    );
+    assert!(
+        Filter::parse("amount > 1 amount")
+            .unwrap_err()
+            .message
+            .contains("лишний")
+    );
+    assert!(
+        Filter::parse("amount = 1")
+            .unwrap_err()
+            .message
+            .contains("неожиданный символ")
+    );
+}
+
+#[test]
+fn filter_limits_nesting_depth() {
+    let nested =
+        |depth: usize, open: &str| format!("{}amount > 1{}", open.repeat(depth), ")".repeat(depth));
+
+    assert!(Filter::parse(&nested(64, "(")).is_ok());
+    let parens = Filter::parse(&nested(100_000, "(")).unwrap_err();
+    assert_eq!(parens.position, Some(64));
+    assert!(parens.message.contains("глубже 64"));
+
+    let negations = Filter::parse(&format!("{}amount > 1", "!".repeat(100_000))).unwrap_err();
+    assert_eq!(negations.position, Some(64));
+    assert!(
+        Filter::parse(&nested(40, "!("))
+            .unwrap_err()
+            .message
+            .contains("глубже")
+    );
+}
@@ -0,0 +1,140 @@
+use super::*;
+use crate::print;
+use crate::records::{Align, FixedColumn, FixedLayout, FixedRecords};
+
+fn sample_layout() -> FixedLayout {
+    FixedLayout::from_toml(
+        r#"
+        [[column]]
+        field = "from_client"
+        start = 1
+        width = 6
+
+        [[column]]
+        field = "from_bank"
+        start = 7
+        width = 6
+
+        [[column]]
+        field = "to_client"
+        start = 13
+        width = 6
+
+        [[column]]
+        field = "to_bank"
+        start = 19
+        width = 6
+
+        [[column]]
+        field = "transaction"
+        start = 25
+        width = 5
+        align = "right"
+        pad = "0"
+
+        [[column]]
+        field = "amount"
+        start = 31
+        width = 8
+        align = "right"
+        pad = "0"
+        decimals = 2
+
+        [[column]]
+        field = "date"
+        start = 39
+        width = 8
+        date_format = "%Y%m%d"
+        "#,
+    )
+    .unwrap()
+}
+
+fn sample_fixed_data() -> &'static str {
+    "Alice bank_aBob   bank_b00123 0005000520250124\n"
+}
+
+#[test]
+fn parse_with_layout_parses_fixed_width_input() {
+    let data = Cursor::new(format!("{}\n", sample_fixed_data()));
+
+    let records = FixedRecords::parse_with_layout(data, sample_layout()).unwrap();
+
+    assert_eq!(records.list(), &vec![sample_csv_record()]);
+}
+
+#[test]
+fn print_fn_writes_fixed_width_lines() {
+    let records = FixedRecords::from(vec![sample_csv_record()]).with_layout(sample_layout());
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    assert_eq!(String::from_utf8(buffer).unwrap(), sample_fixed_data());
+}
+
+#[test]
+fn print_fn_fails_when_value_does_not_fit() {
+    let mut record = sample_csv_record();
+    record.from_client = "Alexander".into();
+    let records = FixedRecords::from(vec![record]).with_layout(sample_layout());
+
+    let err = print(Vec::new(), &records).err().unwrap();
+
+    let expected = "не получилось сохранить результат: \
+        значение `Alexander` поля `from_client` не помещается в 6 символов";
+    assert_eq!(err.to_string(), expected);
+}
+
+#[test]
+fn validate_rejects_overlapping_columns() {
+    let mut layout = sample_layout();
+    layout.columns[1].start = 5;
+
+    let err = layout.validate().err().unwrap();
+
+    assert_eq!(err, "колонки `from_client` и `from_bank` пересекаются");
+}
+
+#[test]
+fn fixed_details_survive_round_trip() {
+    let mut layout = sample_layout();
+    for (field, start, width) in [
+        ("description", 47, 20),
+        ("end_to_end_id", 67, 10),
+        ("remittance_reference", 77, 8),
+        ("value_date", 85, 10),
+    ] {
+        layout.columns.push(FixedColumn {
+            field: field.into(),
+            start,
+            width,
+            align: Align::Left,
+            pad: ' ',
+            decimals: None,
+            date_format: None,
+        });
+    }
+    let records = FixedRecords::from(vec![detailed_csv_record(), sample_csv_record()])
+        .with_layout(layout.clone());
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let parsed = FixedRecords::parse_with_layout(Cursor::new(buffer), layout).unwrap();
+    assert_eq!(
+        parsed.list(),
+        &vec![detailed_csv_record(), sample_csv_record()]
+    );
+}
+
+#[test]
+fn print_fn_fails_when_detail_has_no_column() {
+    let records = FixedRecords::from(vec![detailed_csv_record()]).with_layout(sample_layout());
+
+    let err = print(Vec::new(), &records).err().unwrap();
+
+    let expected = "не получилось сохранить результат: \
+        у операции `123` заполнено поле `description`, но для него нет колонки";
+    assert_eq!(err.to_string(), expected);
+}
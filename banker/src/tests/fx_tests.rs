@@ -0,0 +1,99 @@
+use super::*;
+use crate::CsvRecords;
+use crate::fx::{self, RateTable};
+
+use chrono::NaiveDate;
+
+fn rates() -> RateTable {
+    RateTable::from_csv(Cursor::new(
+        "date,from,to,rate\n\
+        2025-01-23,USD,RUB,100.5\n\
+        24-01-2025,usd,rub,101.25\n\
+        2025-01-24,RUB,EUR,0.01\n",
+    ))
+    .unwrap()
+}
+
+fn date(date: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap()
+}
+
+#[test]
+fn rate_falls_back_to_previous_date() {
+    let rates = rates();
+
+    assert_eq!(
+        rates.rate("USD", "RUB", date("2025-01-24"), 0),
+        Some((date("2025-01-24"), 101.25))
+    );
+    assert_eq!(
+        rates.rate("USD", "RUB", date("2025-01-26"), 3),
+        Some((date("2025-01-24"), 101.25))
+    );
+    assert_eq!(rates.rate("USD", "RUB", date("2025-01-26"), 1), None);
+    assert_eq!(rates.rate("USD", "RUB", date("2025-01-22"), 7), None);
+}
+
+#[test]
+fn rate_uses_inverse_pair() {
+    assert_eq!(
+        rates().rate("EUR", "RUB", date("2025-01-24"), 0),
+        Some((date("2025-01-24"), 100.0))
+    );
+}
+
+#[test]
+fn convert_records_applied_rate_in_csv() {
+    let mut record = sample_csv_record();
+    record.date = "25-01-2025".into();
+
+    let records = fx::convert(vec![record], &rates(), "usd", "RUB", 1).unwrap();
+    assert_eq!(records[0].amount, 50630.06);
+
+    let mut buffer = Vec::new();
+    crate::print(&mut buffer, &CsvRecords::from(records)).unwrap();
+    let csv = String::from_utf8(buffer).unwrap();
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next(),
+        Some(
+            "from_client,from_bank,to_client,to_bank,transaction,amount,date,\
+            original_amount,original_currency,currency,rate,rate_date"
+        )
+    );
+    assert_eq!(
+        lines.next(),
+        Some("Alice,bank_a,Bob,bank_b,123,50630.06,25-01-2025,500.05,USD,RUB,101.25,24-01-2025")
+    );
+}
+
+#[test]
+fn convert_fails_without_rate() {
+    let err = fx::convert(vec![sample_csv_record()], &rates(), "GBP", "RUB", 7).unwrap_err();
+
+    assert_eq!(
+        err,
+        "операция `123`: нет курса GBP/RUB на 24-01-2025 и 7 дн. до неё"
+    );
+}
+
+#[test]
+fn rate_table_reports_bad_line() {
+    let err = RateTable::from_csv(Cursor::new(
+        "date,from,to,rate\n2025-01-23,USD,RUB,100.5\n2025-13-01,USD,RUB,1\n",
+    ))
+    .unwrap_err();
+
+    assert!(
+        err.starts_with("строка 3: некорректная дата `2025-13-01`"),
+        "{err}"
+    );
+}
+
+#[test]
+fn huge_fallback_does_not_overflow() {
+    assert_eq!(
+        rates().rate("USD", "RUB", date("2025-01-26"), u64::MAX),
+        Some((date("2025-01-24"), 101.25))
+    );
+}
@@ -0,0 +1,99 @@
+use crate::generate::{self, Amounts, GenerateOptions};
+use crate::records::parse_date;
+use crate::validation;
+
+#[test]
+fn same_seed_gives_same_records() {
+    let options = GenerateOptions {
+        seed: 42,
+        count: 50,
+        ..Default::default()
+    };
+
+    let first = generate::generate(&options).unwrap();
+    let second = generate::generate(&options).unwrap();
+    let other = generate::generate(&GenerateOptions {
+        seed: 43,
+        ..options
+    })
+    .unwrap();
+
+    assert_eq!(first.len(), 50);
+    assert_eq!(first, second);
+    assert_ne!(first, other);
+}
+
+#[test]
+fn records_are_sorted_by_date_within_range() {
+    let options = GenerateOptions {
+        count: 200,
+        clients: 5,
+        from: "25-01-2025".into(),
+        to: "05-02-2025".into(),
+        ..Default::default()
+    };
+    let (from, to) = (
+        parse_date(&options.from).unwrap(),
+        parse_date(&options.to).unwrap(),
+    );
+
+    let records = generate::generate(&options).unwrap();
+    let dates: Vec<_> = records
+        .iter()
+        .map(|r| parse_date(&r.date).unwrap())
+        .collect();
+
+    assert!(dates.is_sorted());
+    assert!(dates.iter().all(|date| (from..=to).contains(date)));
+    assert_eq!(records[0].transaction, "1");
+    assert_eq!(records[199].transaction, "200");
+    assert!(records.iter().all(|r| r.from_client != r.to_client));
+}
+
+#[test]
+fn amounts_follow_uniform_bounds_and_banks_are_valid() {
+    let options = GenerateOptions {
+        count: 500,
+        amounts: "uniform:10:20".parse().unwrap(),
+        ..Default::default()
+    };
+
+    let records = generate::generate(&options).unwrap();
+
+    assert!(records.iter().all(|r| (10.0..=20.0).contains(&r.amount)));
+    assert!(validation::records(&records).is_empty());
+}
+
+#[test]
+fn parse_amounts() {
+    assert_eq!(
+        "lognormal:5000:1.5".parse::<Amounts>(),
+        Ok(Amounts::LogNormal {
+            median: 5000.0,
+            sigma: 1.5
+        })
+    );
+    assert_eq!(
+        Amounts::Uniform { min: 1.0, max: 2.5 }.to_string(),
+        "uniform:1:2.5"
+    );
+    assert!("uniform:20:10".parse::<Amounts>().is_err());
+    assert!("normal:1:2".parse::<Amounts>().is_err());
+    assert!("lognormal:x:1".parse::<Amounts>().is_err());
+}
+
+#[test]
+fn invalid_options_are_rejected() {
+    let reversed = GenerateOptions {
+        from: "02-01-2025".into(),
+        to: "01-01-2025".into(),
+        ..Default::default()
+    };
+    let lonely = GenerateOptions {
+        clients: 1,
+        ..Default::default()
+    };
+
+    assert!(generate::generate(&reversed).is_err());
+    assert!(generate::generate(&lonely).is_err());
+}
@@ -0,0 +1,130 @@
+use super::*;
+use crate::fx::Conversion;
+use crate::print;
+use crate::records::{BeancountRecords, JournalOptions, LedgerRecords};
+
+#[test]
+fn print_fn_writes_ledger_journal() {
+    let records: LedgerRecords = vec![sample_csv_record()].into();
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let expected = "2025-01-24 (123) Alice -> Bob\n    \
+        Assets:bank_b:Bob  500.05 RUB\n    \
+        Assets:bank_a:Alice  -500.05 RUB\n\n";
+    assert_eq!(String::from_utf8(buffer).unwrap(), expected);
+}
+
+#[test]
+fn print_fn_writes_beancount_journal() {
+    let records: BeancountRecords = vec![sample_csv_record()].into();
+    let records = records.with_options(JournalOptions {
+        from_account: "Assets:Banks:{bank}".into(),
+        to_account: "Expenses:{client}".into(),
+        commodity: "usd".into(),
+    });
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let expected = "2025-01-24 open Assets:Banks:Bank-a\n\
+        2025-01-24 open Expenses:Bob\n\
+        \n\
+        2025-01-24 * \"Alice\" \"Alice -> Bob\"\n  \
+        transaction: \"123\"\n  \
+        Expenses:Bob  500.05 USD\n  \
+        Assets:Banks:Bank-a  -500.05 USD\n";
+    assert_eq!(String::from_utf8(buffer).unwrap(), expected);
+}
+
+#[test]
+fn print_fn_sanitizes_journal_accounts() {
+    let mut record = sample_csv_record();
+    record.from_client = "ООО \"Ромашка\": филиал".into();
+    record.from_bank = "  ".into();
+    let records: BeancountRecords = vec![record].into();
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let journal = String::from_utf8(buffer).unwrap();
+    assert!(journal.contains("open Assets:Unknown:ООО-Ромашка-филиал\n"));
+}
+
+#[test]
+fn journals_use_currency_of_converted_amounts() {
+    let converted = || {
+        let mut record = sample_csv_record();
+        record.conversion = Some(Conversion {
+            original_amount: 5.0,
+            original_currency: "USD".into(),
+            currency: "eur".into(),
+            rate: 100.01,
+            rate_date: "24-01-2025".into(),
+        });
+        vec![record, sample_csv_record()]
+    };
+
+    let mut ledger = Vec::new();
+    print(&mut ledger, &LedgerRecords::from(converted())).unwrap();
+    let mut beancount = Vec::new();
+    print(&mut beancount, &BeancountRecords::from(converted())).unwrap();
+
+    let ledger = String::from_utf8(ledger).unwrap();
+    assert!(ledger.contains("Assets:bank_b:Bob  500.05 eur\n"));
+    assert!(ledger.contains("Assets:bank_b:Bob  500.05 RUB\n"));
+    let beancount = String::from_utf8(beancount).unwrap();
+    assert!(beancount.contains("Assets:Bank-b:Bob  500.05 EUR\n"));
+    assert!(beancount.contains("Assets:Bank-b:Bob  500.05 RUB\n"));
+}
+
+#[test]
+fn journals_record_applied_rate() {
+    let converted = || {
+        let mut record = sample_csv_record();
+        record.conversion = Some(Conversion {
+            original_amount: 5.0,
+            original_currency: "usd".into(),
+            currency: "RUB".into(),
+            rate: 100.01,
+            rate_date: "23-01-2025".into(),
+        });
+        vec![record]
+    };
+
+    let mut ledger = Vec::new();
+    print(&mut ledger, &LedgerRecords::from(converted())).unwrap();
+    let mut beancount = Vec::new();
+    print(&mut beancount, &BeancountRecords::from(converted())).unwrap();
+
+    let ledger = String::from_utf8(ledger).unwrap();
+    assert!(ledger.contains(
+        "    ; original_amount: 5.00 usd\n    ; rate: 100.01\n    ; rate_date: 2025-01-23\n"
+    ));
+    let beancount = String::from_utf8(beancount).unwrap();
+    assert!(
+        beancount
+            .contains("  original_amount: 5.00 USD\n  rate: 100.01\n  rate_date: 2025-01-23\n")
+    );
+}
+
+#[test]
+fn journals_do_not_print_negative_zero() {
+    let tiny = || {
+        let mut record = sample_csv_record();
+        record.amount = 0.004;
+        vec![record]
+    };
+
+    let mut ledger = Vec::new();
+    print(&mut ledger, &LedgerRecords::from(tiny())).unwrap();
+    let mut beancount = Vec::new();
+    print(&mut beancount, &BeancountRecords::from(tiny())).unwrap();
+
+    for journal in [ledger, beancount] {
+        let journal = String::from_utf8(journal).unwrap();
+        assert!(!journal.contains("-0.00"), "{journal}");
+        assert_eq!(journal.matches(" 0.00 RUB\n").count(), 2);
+    }
+}
@@ -0,0 +1,68 @@
+use super::*;
+use crate::CsvRecords;
+use crate::merge;
+
+fn daily_statement(day: &str, transactions: &[&str]) -> Vec<CsvRecord> {
+    transactions
+        .iter()
+        .map(|&transaction| {
+            let mut record = sample_csv_record();
+            record.transaction = transaction.into();
+            record.date = day.into();
+            record
+        })
+        .collect()
+}
+
+fn transactions(records: &[CsvRecord]) -> Vec<&str> {
+    records.iter().map(|r| r.transaction.as_str()).collect()
+}
+
+#[test]
+fn merge_keeps_source_order_without_sorting() {
+    let sources = vec![
+        daily_statement("25-01-2025", &["3"]),
+        daily_statement("24-01-2025", &["1", "2"]),
+    ];
+
+    let records = merge::merge(sources, false).unwrap();
+
+    assert_eq!(transactions(&records), vec!["3", "1", "2"]);
+}
+
+#[test]
+fn merge_by_date_is_stable_across_sources() {
+    let mut second = daily_statement("24-01-2025", &["4"]);
+    second.extend(daily_statement("26-01-2025", &["5"]));
+    let sources = vec![
+        daily_statement("25-01-2025", &["3"]),
+        daily_statement("24-01-2025", &["1", "2"]),
+        second,
+    ];
+
+    let records = merge::merge(sources, true).unwrap();
+
+    assert_eq!(transactions(&records), vec!["1", "2", "4", "3", "5"]);
+}
+
+#[test]
+fn tag_sets_source_written_to_csv() {
+    let records = merge::tag(daily_statement("24-01-2025", &["1", "2"]), "day1.csv");
+    let records: CsvRecords = merge::merge(vec![records], false).unwrap().into();
+
+    let mut buffer = Vec::new();
+    crate::print(&mut buffer, &records).unwrap();
+
+    let csv = String::from_utf8(buffer).unwrap();
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next(),
+        Some(
+            "from_client,from_bank,to_client,to_bank,transaction,amount,date,source_file,source_row"
+        )
+    );
+    assert_eq!(
+        lines.nth(1),
+        Some("Alice,bank_a,Bob,bank_b,2,500.05,24-01-2025,day1.csv,2")
+    );
+}
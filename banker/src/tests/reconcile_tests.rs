@@ -0,0 +1,135 @@
+use super::*;
+use crate::reconcile::{self, MatchRule, Pair, ReconcileOptions, Split};
+
+fn record(transaction: &str, to_client: &str, amount: f64, date: &str) -> CsvRecord {
+    let mut record = sample_csv_record();
+    record.transaction = transaction.into();
+    record.to_client = to_client.into();
+    record.amount = amount;
+    record.date = date.into();
+    record
+}
+
+fn options() -> ReconcileOptions {
+    ReconcileOptions {
+        amount_epsilon: 0.01,
+        date_days: 2,
+        name_similarity: 0.8,
+    }
+}
+
+#[test]
+fn reconcile_matches_transaction_before_tolerance() {
+    let statement = vec![
+        record("B-1", "Bob", 100.0, "24-01-2025"),
+        record("B-2", "OOO Romashka", 250.0, "25-01-2025"),
+    ];
+    let ledger = vec![
+        record("L-7", "romashka ooo", 250.01, "27-01-2025"),
+        record("B-1", "Bob", 99.0, "24-01-2025"),
+    ];
+
+    let result = reconcile::reconcile(&statement, &ledger, &options()).unwrap();
+
+    assert_eq!(
+        result.pairs,
+        vec![
+            Pair {
+                statement: 0,
+                ledger: 1,
+                rule: MatchRule::Transaction,
+            },
+            Pair {
+                statement: 1,
+                ledger: 0,
+                rule: MatchRule::Tolerance,
+            },
+        ]
+    );
+    assert!(result.unmatched_statement.is_empty());
+    assert!(result.unmatched_ledger.is_empty());
+}
+
+#[test]
+fn reconcile_leaves_records_outside_tolerance_unmatched() {
+    let statement = vec![
+        record("B-1", "Bob", 100.0, "24-01-2025"),
+        record("B-2", "Bob", 200.0, "24-01-2025"),
+    ];
+    let ledger = vec![
+        record("L-1", "Bob", 100.02, "24-01-2025"),
+        record("L-2", "Carol", 200.0, "24-01-2025"),
+        record("L-3", "Bob", 200.0, "28-01-2025"),
+    ];
+
+    let result = reconcile::reconcile(&statement, &ledger, &options()).unwrap();
+
+    assert!(result.pairs.is_empty());
+    assert_eq!(result.unmatched_statement, vec![0, 1]);
+    assert_eq!(result.unmatched_ledger, vec![0, 1, 2]);
+}
+
+#[test]
+fn reconcile_finds_one_to_many_matches() {
+    let statement = vec![
+        record("B-1", "Bob", 300.0, "24-01-2025"),
+        record("B-2", "Carol", 40.0, "25-01-2025"),
+        record("B-3", "Carol", 60.0, "25-01-2025"),
+    ];
+    let ledger = vec![
+        record("L-1", "Bob", 120.0, "23-01-2025"),
+        record("L-2", "Carol", 100.0, "25-01-2025"),
+        record("L-3", "Bob", 180.0, "24-01-2025"),
+    ];
+
+    let result = reconcile::reconcile(&statement, &ledger, &options()).unwrap();
+
+    assert_eq!(
+        result.splits,
+        vec![
+            Split {
+                statement: vec![0],
+                ledger: vec![0, 2],
+            },
+            Split {
+                statement: vec![1, 2],
+                ledger: vec![1],
+            },
+        ]
+    );
+    assert!(result.pairs.is_empty());
+}
+
+#[test]
+fn reconcile_rejects_invalid_options() {
+    let mut options = options();
+    options.name_similarity = 1.5;
+
+    let err = reconcile::reconcile(&[], &[], &options).unwrap_err();
+
+    assert_eq!(err, "похожесть имён задаётся числом от 0 до 1");
+}
+
+#[test]
+fn reconcile_prints_json_with_records() {
+    let statement = vec![
+        record("B-1", "Bob", 100.0, "24-01-2025"),
+        record("B-2", "Alice", 300.0, "24-01-2025"),
+    ];
+    let ledger = vec![
+        record("B-1", "Bob", 100.0, "24-01-2025"),
+        record("L-5", "Carol", 50.0, "24-01-2025"),
+    ];
+    let result = reconcile::reconcile(&statement, &ledger, &options()).unwrap();
+
+    let mut buffer = Vec::new();
+    result.print_json(&mut buffer, &statement, &ledger).unwrap();
+
+    let json: serde_json::Value = serde_json::from_slice(&buffer).unwrap();
+    assert_eq!(json["pairs"][0]["rule"], "transaction");
+    assert_eq!(json["pairs"][0]["statement"]["transaction"], "B-1");
+    assert_eq!(json["pairs"][0]["ledger"]["amount"], 100.0);
+    assert_eq!(json["splits"], serde_json::json!([]));
+    assert_eq!(json["unmatched_statement"][0]["to_client"], "Alice");
+    assert_eq!(json["unmatched_ledger"][0]["transaction"], "L-5");
+}
@@ -0,0 +1,80 @@
+use super::*;
+use crate::print;
+use crate::records::{HtmlRecords, MarkdownRecords, TableOptions, TableRecords};
+
+fn sample_report_records() -> Vec<CsvRecord> {
+    let mut second = sample_csv_record();
+    second.from_client = "Bob".into();
+    second.from_bank = "bank_b".into();
+    second.to_client = "Alice".into();
+    second.to_bank = "bank_a".into();
+    second.amount = 15.5;
+
+    vec![sample_csv_record(), second]
+}
+
+#[test]
+fn print_fn_writes_aligned_table() {
+    let records: TableRecords = sample_report_records().into();
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let expected = "\
+from_client  from_bank  to_client  to_bank  transaction  amount  date
+-----------  ---------  ---------  -------  -----------  ------  ----------
+Alice        bank_a     Bob        bank_b   123          500.05  24-01-2025
+Bob          bank_b     Alice      bank_a   123           15.50  24-01-2025
+";
+    assert_eq!(String::from_utf8(buffer).unwrap(), expected);
+}
+
+#[test]
+fn print_fn_truncates_table_to_max_width() {
+    let records = TableRecords::from(sample_report_records()).with_options(TableOptions {
+        max_width: Some(60),
+        ..Default::default()
+    });
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let table = String::from_utf8(buffer).unwrap();
+    assert!(table.lines().all(|line| line.chars().count() <= 60));
+    assert!(table.contains("from_c…"));
+    assert!(table.contains("500.05  24-01-2025"));
+}
+
+#[test]
+fn print_fn_writes_markdown_table() {
+    let records: MarkdownRecords = vec![sample_csv_record()].into();
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let expected = "\
+| from_client | from_bank | to_client | to_bank | transaction | amount | date       |
+| ----------- | --------- | --------- | ------- | ----------- | -----: | ---------- |
+| Alice       | bank_a    | Bob       | bank_b  | 123         | 500.05 | 24-01-2025 |
+";
+    assert_eq!(String::from_utf8(buffer).unwrap(), expected);
+}
+
+#[test]
+fn print_fn_writes_html_report_with_bank_totals() {
+    let mut records = sample_report_records();
+    records[0].to_client = "<script>".into();
+    let records: HtmlRecords = records.into();
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let html = String::from_utf8(buffer).unwrap();
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<td>&lt;script&gt;</td>"));
+    assert!(html.contains(
+        "<tr><td>bank_a</td><td class=\"amount\">1</td><td class=\"amount\">500.05</td>\
+        <td class=\"amount\">1</td><td class=\"amount\">15.50</td></tr>"
+    ));
+    assert!(html.contains("<td class=\"amount\">515.55</td>"));
+}
@@ -0,0 +1,102 @@
+use super::*;
+use crate::rules::{Rules, Severity};
+
+use chrono::NaiveDate;
+
+const SAMPLE_RULES: &str = r#"
+[[rule]]
+name = "сумма положительная"
+field = "amount"
+operator = ">"
+value = 0
+
+[[rule]]
+name = "лимит bank_a"
+field = "amount"
+operator = "<"
+value = 500
+severity = "warning"
+when = { from_bank = "bank_a" }
+
+[[rule]]
+field = "date"
+operator = "<="
+value = "today"
+
+[[rule]]
+field = "from_client"
+operator = "!="
+other = "to_client"
+
+[[rule]]
+field = "transaction"
+operator = "unique"
+"#;
+
+fn sample_rule_records() -> Vec<CsvRecord> {
+    let mut future = sample_csv_record();
+    future.transaction = "124".into();
+    future.date = "01-02-2025".into();
+    future.from_bank = "bank_c".into();
+
+    let mut duplicate = sample_csv_record();
+    duplicate.to_client = "Alice".into();
+    duplicate.amount = -1.0;
+
+    vec![sample_csv_record(), future, duplicate]
+}
+
+#[test]
+fn check_reports_violations_by_record() {
+    let rules = Rules::from_toml(SAMPLE_RULES).unwrap();
+    let today = NaiveDate::from_ymd_opt(2025, 1, 31).unwrap();
+
+    let report = rules.check_at(&sample_rule_records(), today);
+
+    let violations: Vec<(usize, &str, Severity)> = report
+        .violations
+        .iter()
+        .map(|v| (v.index, v.rule.as_str(), v.severity))
+        .collect();
+    let expected = vec![
+        (0, "лимит bank_a", Severity::Warning),
+        (1, "date <= \"today\"", Severity::Error),
+        (2, "сумма положительная", Severity::Error),
+        (2, "from_client != to_client", Severity::Error),
+        (2, "transaction unique", Severity::Error),
+    ];
+    assert_eq!(violations, expected);
+    assert!(report.has_errors());
+    assert_eq!(
+        report.violations[1].to_string(),
+        "ошибка: операция `124` (№2), правило `date <= \"today\"`: \
+        нарушено условие `date <= \"today\"`: date = `01-02-2025`"
+    );
+}
+
+#[test]
+fn check_passes_valid_records() {
+    let rules = Rules::from_toml(SAMPLE_RULES).unwrap();
+    let today = NaiveDate::from_ymd_opt(2025, 1, 31).unwrap();
+    let mut record = sample_csv_record();
+    record.amount = 100.0;
+
+    let report = rules.check_at(&[record], today);
+
+    assert!(report.violations.is_empty());
+    assert_eq!(report.to_string(), "ошибок: 0, предупреждений: 0");
+}
+
+#[test]
+fn from_toml_rejects_unknown_field_and_type_mismatch() {
+    let unknown = "[[rule]]\nfield = \"currency\"\noperator = \"==\"\nvalue = \"RUB\"\n";
+    let err = Rules::from_toml(unknown).unwrap_err();
+    assert_eq!(err.to_string(), "неизвестное поле `currency`");
+
+    let mismatch = "[[rule]]\nfield = \"amount\"\noperator = \">\"\nvalue = \"много\"\n";
+    let err = Rules::from_toml(mismatch).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "правило для `amount`: ожидалось число, получено `много`"
+    );
+}
@@ -0,0 +1,99 @@
+use super::*;
+use crate::CsvRecords;
+use crate::print;
+use crate::records::{Columns, TableOptions, TableRecords};
+use crate::sort::{self, SortKey};
+
+fn sample_sort_records() -> Vec<CsvRecord> {
+    let mut small = sample_csv_record();
+    small.transaction = "124".into();
+    small.amount = 90.0;
+
+    let mut earlier = sample_csv_record();
+    earlier.transaction = "125".into();
+    earlier.amount = 1000.0;
+    earlier.date = "31-12-2024".into();
+
+    let mut same = sample_csv_record();
+    same.transaction = "126".into();
+
+    vec![sample_csv_record(), small, earlier, same]
+}
+
+fn transactions(records: &[CsvRecord]) -> Vec<&str> {
+    records.iter().map(|r| r.transaction.as_str()).collect()
+}
+
+#[test]
+fn sort_orders_by_date_then_amount_descending() {
+    let keys = SortKey::parse_list("date,-amount").unwrap();
+
+    let records = sort::sort(sample_sort_records(), &keys).unwrap();
+
+    assert_eq!(keys, vec![SortKey::asc("date"), SortKey::desc("amount")]);
+    assert_eq!(transactions(&records), vec!["125", "123", "126", "124"]);
+}
+
+#[test]
+fn sort_compares_amounts_as_numbers_and_keeps_ties_stable() {
+    let mut records = sample_sort_records();
+    records[1].amount = 9.0;
+    records[2].amount = 10.0;
+
+    let records = sort::sort(records, &[SortKey::asc("amount")]).unwrap();
+
+    assert_eq!(transactions(&records), vec!["124", "125", "123", "126"]);
+}
+
+#[test]
+fn sort_rejects_unknown_field_and_invalid_date() {
+    let mut records = sample_sort_records();
+    records[3].date = "2025/01/24".into();
+
+    assert!(
+        SortKey::parse_list("date,-sum")
+            .unwrap_err()
+            .contains("`sum`")
+    );
+    assert!(
+        sort::sort(records, &[SortKey::asc("date")])
+            .unwrap_err()
+            .contains("`126`")
+    );
+}
+
+#[test]
+fn print_fn_writes_selected_csv_columns() {
+    let columns: Columns = "date,amount,to_client".parse().unwrap();
+    let mut record = sample_csv_record();
+    record.amount = 215.0;
+    let records = CsvRecords::from(vec![record]).with_columns(columns);
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "date,amount,to_client\n24-01-2025,215.0,Bob\n"
+    );
+}
+
+#[test]
+fn print_fn_writes_selected_table_columns() {
+    let records = TableRecords::from(vec![sample_csv_record()]).with_options(TableOptions {
+        columns: Columns::new(["amount", "from_client"]).unwrap(),
+        ..Default::default()
+    });
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let expected = "\
+amount  from_client
+------  -----------
+500.05  Alice
+";
+    assert_eq!(String::from_utf8(buffer).unwrap(), expected);
+    assert!("date,date".parse::<Columns>().is_err());
+    assert!("date,bank".parse::<Columns>().is_err());
+}
@@ -0,0 +1,115 @@
+use super::*;
+use crate::split::{self, Template};
+
+use std::path::PathBuf;
+
+fn record(transaction: &str, to_bank: &str, date: &str) -> CsvRecord {
+    let mut record = sample_csv_record();
+    record.transaction = transaction.into();
+    record.to_bank = to_bank.into();
+    record.date = date.into();
+    record
+}
+
+fn layout(outputs: &[split::Output]) -> Vec<(PathBuf, Vec<&str>)> {
+    outputs
+        .iter()
+        .map(|output| {
+            let transactions = output
+                .records
+                .iter()
+                .map(|r| r.transaction.as_str())
+                .collect();
+            (output.path.clone(), transactions)
+        })
+        .collect()
+}
+
+#[test]
+fn split_by_bank_and_month() {
+    let records = vec![
+        record("1", "bank_b", "24-01-2025"),
+        record("2", "bank_c", "25-01-2025"),
+        record("3", "bank_b", "03-02-2025"),
+        record("4", "bank_b", "31-01-2025"),
+    ];
+    let template: Template = "out/{to_bank}/{date:%Y-%m}.csv".parse().unwrap();
+    assert_eq!(template.to_string(), "out/{to_bank}/{date:%Y-%m}.csv");
+
+    let outputs = split::split(records, &template, None).unwrap();
+
+    assert_eq!(
+        layout(&outputs),
+        vec![
+            ("out/bank_b/2025-01.csv".into(), vec!["1", "4"]),
+            ("out/bank_c/2025-01.csv".into(), vec!["2"]),
+            ("out/bank_b/2025-02.csv".into(), vec!["3"]),
+        ]
+    );
+}
+
+#[test]
+fn split_by_max_records_numbers_parts() {
+    let records = (1..=5)
+        .map(|i| record(&i.to_string(), "bank_b", "24-01-2025"))
+        .collect();
+    let template: Template = "out/{to_bank}-{part}.csv".parse().unwrap();
+
+    let outputs = split::split(records, &template, Some(2)).unwrap();
+
+    assert_eq!(
+        layout(&outputs),
+        vec![
+            ("out/bank_b-1.csv".into(), vec!["1", "2"]),
+            ("out/bank_b-2.csv".into(), vec!["3", "4"]),
+            ("out/bank_b-3.csv".into(), vec!["5"]),
+        ]
+    );
+}
+
+#[test]
+fn split_max_records_requires_part() {
+    let template: Template = "out/{to_bank}.csv".parse().unwrap();
+
+    let err = split::split(vec![sample_csv_record()], &template, Some(10)).unwrap_err();
+
+    assert!(err.contains("{part}"), "{err}");
+}
+
+#[test]
+fn template_replaces_slashes_in_values() {
+    let template: Template = "{to_bank}.csv".parse().unwrap();
+
+    let path = template
+        .render(&record("1", "bank/b", "24-01-2025"), 1)
+        .unwrap();
+
+    assert_eq!(path, "bank_b.csv");
+}
+
+#[test]
+fn template_keeps_files_inside_output_dir() {
+    let template: Template = "out/{to_client}/{to_bank}.csv".parse().unwrap();
+    let mut record = record("1", "..", "24-01-2025");
+    record.to_client = "..".into();
+
+    assert_eq!(template.render(&record, 1).unwrap(), "out/_/_.csv");
+
+    record.to_client = ".hidden".into();
+    assert!(template.render(&record, 1).is_err());
+    record.to_client = "a\0b".into();
+    assert!(template.render(&record, 1).is_err());
+}
+
+#[test]
+fn template_rejects_invalid_placeholders() {
+    for (template, expected) in [
+        ("out/{to_bank.csv", "не закрыта скобка"),
+        ("out/{bank}.csv", "неизвестное поле `bank`"),
+        ("out/{amount:%Y}.csv", "только для `date`"),
+        ("out/{date:%Q}.csv", "некорректный формат даты"),
+    ] {
+        let err = template.parse::<Template>().unwrap_err();
+        assert!(err.contains(expected), "{template}: {err}");
+    }
+}
@@ -0,0 +1,102 @@
+use super::*;
+use crate::records::{SqliteMode, SqliteRecords};
+
+use std::path::PathBuf;
+
+fn temp_db(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("banker-{}-{name}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn sample_records() -> SqliteRecords {
+    let mut second = sample_csv_record();
+    second.from_client = "Bob".into();
+    second.amount = 10.0;
+    second.date = "01-02-2025".into();
+
+    vec![sample_csv_record(), second].into()
+}
+
+#[test]
+fn sqlite_records_survive_round_trip() {
+    let path = temp_db("round-trip");
+
+    sample_records().save(&path).unwrap();
+    let records = SqliteRecords::load(&path, None).unwrap();
+
+    assert_eq!(records.list().len(), 2);
+    assert_eq!(records.list().first().unwrap(), &sample_csv_record());
+}
+
+#[test]
+fn save_appends_or_replaces_records() {
+    let path = temp_db("modes");
+
+    sample_records().save(&path).unwrap();
+    sample_records()
+        .with_mode(SqliteMode::Append)
+        .save(&path)
+        .unwrap();
+    assert_eq!(SqliteRecords::load(&path, None).unwrap().list().len(), 4);
+
+    sample_records().save(&path).unwrap();
+    assert_eq!(SqliteRecords::load(&path, None).unwrap().list().len(), 2);
+}
+
+#[test]
+fn load_applies_where_clause() {
+    let path = temp_db("filter");
+
+    sample_records().save(&path).unwrap();
+    let records = SqliteRecords::load(&path, Some("date >= '2025-02-01'")).unwrap();
+
+    assert_eq!(records.list().len(), 1);
+    assert_eq!(records.list().first().unwrap().date, "01-02-2025");
+}
+
+#[test]
+fn sqlite_details_survive_round_trip() {
+    let path = temp_db("details");
+
+    SqliteRecords::from(vec![detailed_csv_record(), sample_csv_record()])
+        .save(&path)
+        .unwrap();
+    let records = SqliteRecords::load(&path, Some("value_date = '2025-01-27'")).unwrap();
+
+    assert_eq!(records.list(), &vec![detailed_csv_record()]);
+}
+
+#[test]
+fn save_adds_detail_columns_to_old_table() {
+    let path = temp_db("old-table");
+    rusqlite::Connection::open(&path)
+        .unwrap()
+        .execute_batch(
+            "CREATE TABLE transactions (
+                id INTEGER PRIMARY KEY,
+                from_client TEXT NOT NULL,
+                from_bank TEXT NOT NULL,
+                to_client TEXT NOT NULL,
+                to_bank TEXT NOT NULL,
+                transaction_id TEXT NOT NULL,
+                amount REAL NOT NULL,
+                date TEXT NOT NULL
+            ) STRICT;
+            INSERT INTO transactions
+                (from_client, from_bank, to_client, to_bank, transaction_id, amount, date)
+                VALUES ('Alice', 'bank_a', 'Bob', 'bank_b', '123', 500.05, '2025-01-24');",
+        )
+        .unwrap();
+
+    SqliteRecords::from(vec![detailed_csv_record()])
+        .with_mode(SqliteMode::Append)
+        .save(&path)
+        .unwrap();
+    let records = SqliteRecords::load(&path, None).unwrap();
+
+    assert_eq!(
+        records.list(),
+        &vec![sample_csv_record(), detailed_csv_record()]
+    );
+}
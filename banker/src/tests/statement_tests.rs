@@ -0,0 +1,57 @@
+use super::*;
+use crate::statement::{BalanceMismatch, Period, Statement};
+
+use chrono::NaiveDate;
+
+fn sample_statement() -> Statement {
+    let mut debit = sample_csv_record();
+    debit.amount = -0.1;
+    let mut credit = sample_csv_record();
+    credit.amount = 0.2;
+
+    Statement {
+        account: "40817810099910004312".into(),
+        period: Period {
+            start: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            end: NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+        },
+        opening_balance: 1000.0,
+        closing_balance: 1500.15,
+        entries: vec![sample_csv_record(), debit, credit],
+    }
+}
+
+#[test]
+fn check_balance_accepts_matching_balances() {
+    let statement = sample_statement();
+
+    assert_eq!(statement.total(), 500.15);
+    assert_eq!(statement.check_balance(), Ok(()));
+}
+
+#[test]
+fn check_balance_reports_discrepancy() {
+    let mut statement = sample_statement();
+    statement.closing_balance = 1499.0;
+
+    let err = statement.check_balance().err().unwrap();
+
+    let expected = BalanceMismatch {
+        expected: 1500.15,
+        actual: 1499.0,
+        discrepancy: -1.15,
+    };
+    assert_eq!(err, expected);
+    assert_eq!(
+        err.to_string(),
+        "остатки не сходятся: ожидался исходящий остаток 1500.15, в выписке 1499.00, расхождение -1.15"
+    );
+}
+
+#[test]
+fn check_balance_of_empty_statement_compares_opening_and_closing() {
+    let mut statement = sample_statement();
+    statement.entries.clear();
+
+    assert_eq!(statement.check_balance().unwrap_err().discrepancy, 500.15);
+}
@@ -0,0 +1,97 @@
+use super::*;
+use crate::stats::{self, Amount};
+
+fn sample_stats_records() -> Vec<CsvRecord> {
+    let mut second = sample_csv_record();
+    second.transaction = "124".into();
+    second.amount = 0.1;
+    second.date = "23-01-2025".into();
+
+    let mut third = sample_csv_record();
+    third.transaction = "125".into();
+    third.amount = 0.2;
+
+    let mut other = sample_csv_record();
+    other.transaction = "126".into();
+    other.from_client = "Bob".into();
+    other.from_bank = "bank_b".into();
+    other.amount = 15.5;
+
+    vec![sample_csv_record(), second, third, other]
+}
+
+#[test]
+fn aggregate_groups_by_sender_with_exact_totals() {
+    let summary = stats::aggregate(&sample_stats_records(), &["from_client".into()]).unwrap();
+
+    let alice = &summary.groups[0];
+    assert_eq!(summary.groups.len(), 2);
+    assert_eq!(alice.key, vec!["Alice"]);
+    assert_eq!(alice.count, 3);
+    assert_eq!(alice.sum, Amount::from_cents(50035));
+    assert_eq!(alice.min, Amount::from(0.1));
+    assert_eq!(alice.max, Amount::from(500.05));
+    assert_eq!(alice.avg.to_string(), "166.78");
+}
+
+#[test]
+fn aggregate_orders_dates_chronologically() {
+    let mut records = sample_stats_records();
+    records[3].date = "02-02-2024".into();
+
+    let summary = stats::aggregate(&records, &["date".into()]).unwrap();
+
+    let dates: Vec<&str> = summary.groups.iter().map(|g| g.key[0].as_str()).collect();
+    assert_eq!(dates, vec!["02-02-2024", "23-01-2025", "24-01-2025"]);
+    assert!(stats::aggregate(&records, &["day".into()]).is_err());
+}
+
+#[test]
+fn summary_prints_csv_and_json() {
+    let group_by = vec!["from_bank".into(), "to_bank".into()];
+    let summary = stats::aggregate(&sample_stats_records(), &group_by).unwrap();
+
+    let mut csv = Vec::new();
+    summary.print_csv(&mut csv).unwrap();
+    let mut json = Vec::new();
+    summary.print_json(&mut json).unwrap();
+
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "from_bank,to_bank,count,sum,min,max,avg\n\
+        bank_a,bank_b,3,500.35,0.10,500.05,166.78\n\
+        bank_b,bank_b,1,15.50,15.50,15.50,15.50\n"
+    );
+    let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+    assert_eq!(
+        json["group_by"],
+        serde_json::json!(["from_bank", "to_bank"])
+    );
+    assert_eq!(json["groups"][0]["sum"], 500.35);
+    assert_eq!(
+        json["groups"][1]["key"],
+        serde_json::json!(["bank_b", "bank_b"])
+    );
+}
+
+#[test]
+fn summary_prints_aligned_table_of_totals() {
+    let summary = stats::aggregate(&sample_stats_records(), &[]).unwrap();
+
+    let mut buffer = Vec::new();
+    summary.print_table(&mut buffer).unwrap();
+
+    let expected = "\
+count     sum   min     max     avg
+-----  ------  ----  ------  ------
+    4  515.85  0.10  500.05  128.96
+";
+    assert_eq!(String::from_utf8(buffer).unwrap(), expected);
+}
+
+#[test]
+fn aggregate_of_no_records_has_no_groups() {
+    let summary = stats::aggregate(&[], &[]).unwrap();
+
+    assert!(summary.groups.is_empty());
+}
@@ -0,0 +1,73 @@
+use super::*;
+use crate::validate;
+use crate::validation::{self, ValidationError};
+
+#[test]
+fn iban_checks_length_and_checksum() {
+    assert_eq!(validation::iban("GB82 WEST 1234 5698 7654 32"), Ok(()));
+    assert_eq!(validation::iban("DE89370400440532013000"), Ok(()));
+    assert_eq!(
+        validation::iban("DE89370400440532013001"),
+        Err(ValidationError::InvalidChecksum)
+    );
+    assert_eq!(
+        validation::iban("DE8937040044053201300"),
+        Err(ValidationError::InvalidLength {
+            expected: 22,
+            actual: 21
+        })
+    );
+    assert_eq!(
+        validation::iban("ZZ89370400440532013000"),
+        Err(ValidationError::UnknownCountry("ZZ".into()))
+    );
+}
+
+#[test]
+fn bic_checks_structure() {
+    assert_eq!(validation::bic("DEUTDEFF"), Ok(()));
+    assert_eq!(validation::bic("DEUTDEFF500"), Ok(()));
+    assert!(matches!(
+        validation::bic("DEU1DEFF"),
+        Err(ValidationError::InvalidStructure(_))
+    ));
+    assert!(matches!(
+        validation::bic("DEUTDEFF5"),
+        Err(ValidationError::InvalidLength { .. })
+    ));
+}
+
+#[test]
+fn ru_account_checks_control_key() {
+    assert_eq!(validation::bik("044525225"), Ok(()));
+    assert_eq!(
+        validation::ru_account("044525225", "40817810899910004312"),
+        Ok(())
+    );
+    assert_eq!(
+        validation::ru_account("044525225", "30101810400000000225"),
+        Ok(())
+    );
+    assert_eq!(
+        validation::ru_account("044525225", "40817810099910004312"),
+        Err(ValidationError::InvalidChecksum)
+    );
+}
+
+#[test]
+fn validate_fn_reports_bank_issues() {
+    let mut record = sample_csv_record();
+    record.from_bank = "044525225/40817810899910004312".into();
+
+    let issues = validate(&[record]);
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(
+        issues[0].to_string(),
+        "операция `123` (№1), to_bank `bank_b`: не похоже ни на IBAN, ни на BIC, ни на БИК"
+    );
+    assert_eq!(
+        validation::bank("Сбербанк"),
+        Err(ValidationError::UnknownFormat)
+    );
+}
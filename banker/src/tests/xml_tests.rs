@@ -0,0 +1,114 @@
+use super::*;
+use crate::records::{XmlOptions, XmlRecords};
+use crate::{parse, print};
+
+fn custom_options() -> XmlOptions {
+    XmlOptions {
+        root: "Payments".into(),
+        record: "Payment".into(),
+        elements: [("amount".to_string(), "Sum".to_string())].into(),
+    }
+}
+
+#[test]
+fn parse_fn_successfuly_parses_valid_xml_input() {
+    let data = Cursor::new(
+        "<?xml version=\"1.0\"?>\n\
+        <records>\n\
+          <record>\n\
+            <date>24-01-2025</date>\n\
+            <from_client>Alice</from_client>\n\
+            <from_bank>bank_a</from_bank>\n\
+            <to_client>Bob</to_client>\n\
+            <to_bank>bank_b</to_bank>\n\
+            <transaction>123</transaction>\n\
+            <amount> 500.05 </amount>\n\
+          </record>\n\
+        </records>\n",
+    );
+
+    let records: XmlRecords = parse(data).unwrap();
+
+    assert_eq!(records.list(), &vec![sample_csv_record()]);
+}
+
+#[test]
+fn xml_records_with_custom_elements_survive_round_trip() {
+    let mut record = sample_csv_record();
+    record.from_client = "Tom & \"Jerry\"".into();
+    let records = XmlRecords::from(vec![record]).with_options(custom_options());
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let xml = String::from_utf8(buffer).unwrap();
+    assert!(xml.contains("<Payment>"));
+    assert!(xml.contains("<Sum>500.05</Sum>"));
+
+    let parsed = XmlRecords::parse_with_options(Cursor::new(xml), custom_options()).unwrap();
+    assert_eq!(
+        parsed.list().first().unwrap().from_client,
+        "Tom & \"Jerry\""
+    );
+}
+
+#[test]
+fn parse_fn_fails_on_missing_xml_field() {
+    let data = Cursor::new("<records><record><from_client>Alice</from_client></record></records>");
+
+    let err = parse::<XmlRecords>(data).err().unwrap();
+
+    let expected = "не получилось распарсить вашу фигню: отсутствует поле `amount`";
+    assert_eq!(err.to_string(), expected);
+}
+
+#[test]
+fn print_schema_describes_custom_elements() {
+    let mut buffer = Vec::new();
+    custom_options().print_schema(&mut buffer).unwrap();
+
+    let xsd = String::from_utf8(buffer).unwrap();
+    assert!(xsd.contains(r#"<xs:element name="Payments">"#));
+    assert!(xsd.contains(r#"<xs:element name="Payment" minOccurs="0" maxOccurs="unbounded">"#));
+    assert!(xsd.contains(r#"<xs:element name="Sum" type="xs:decimal"/>"#));
+}
+
+#[test]
+fn xml_details_survive_round_trip() {
+    let mut options = custom_options();
+    options
+        .elements
+        .insert("description".into(), "Purpose".into());
+    let records = XmlRecords::from(vec![detailed_csv_record(), sample_csv_record()])
+        .with_options(options.clone());
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let xml = String::from_utf8(buffer).unwrap();
+    assert!(xml.contains("<Purpose>Оплата по счёту 17</Purpose>"));
+    assert_eq!(xml.matches("<end_to_end_id>").count(), 1);
+
+    let parsed = XmlRecords::parse_with_options(Cursor::new(xml), options).unwrap();
+    assert_eq!(
+        parsed.list(),
+        &vec![detailed_csv_record(), sample_csv_record()]
+    );
+}
+
+#[test]
+fn parse_fn_fails_on_duplicate_xml_field() {
+    let data = Cursor::new(
+        "<records><record>\
+        <from_client>Alice</from_client><from_client>Mallory</from_client>\
+        </record></records>",
+    );
+
+    let err = parse::<XmlRecords>(data).err().unwrap();
+
+    assert!(
+        err.to_string()
+            .contains("поле `from_client` задано несколько раз"),
+        "{err}"
+    );
+}
@@ -0,0 +1,76 @@
+use super::*;
+use crate::records::{TomlRecords, YamlRecords};
+use crate::{CsvRecords, convert_to, parse, print};
+
+#[test]
+fn yaml_parse_reads_sequence_of_mappings() {
+    let data = Cursor::new(
+        "- from_client: Alice\n  \
+           from_bank: bank_a\n  \
+           to_client: Bob\n  \
+           to_bank: bank_b\n  \
+           transaction: '123'\n  \
+           amount: 500.05\n  \
+           date: 24-01-2025\n",
+    );
+
+    let records: YamlRecords = parse(data).unwrap();
+
+    assert_eq!(records.list(), &vec![sample_csv_record()]);
+}
+
+#[test]
+fn yaml_records_survive_round_trip() {
+    let records: YamlRecords = vec![sample_csv_record(), sample_csv_record()].into();
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let parsed: YamlRecords = parse(Cursor::new(buffer)).unwrap();
+    let csv_records: CsvRecords = convert_to(parsed);
+
+    assert_eq!(csv_records.list().len(), 2);
+    assert_eq!(csv_records.list().first().unwrap(), &sample_csv_record());
+}
+
+#[test]
+fn toml_print_writes_array_of_tables() {
+    let records: TomlRecords = vec![sample_csv_record()].into();
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let expected = "[[transaction]]\n\
+        from_client = \"Alice\"\n\
+        from_bank = \"bank_a\"\n\
+        to_client = \"Bob\"\n\
+        to_bank = \"bank_b\"\n\
+        transaction = \"123\"\n\
+        amount = 500.05\n\
+        date = \"24-01-2025\"\n";
+    assert_eq!(String::from_utf8(buffer).unwrap(), expected);
+}
+
+#[test]
+fn toml_records_survive_round_trip() {
+    let records: TomlRecords = vec![sample_csv_record(), sample_csv_record()].into();
+
+    let mut buffer = Vec::new();
+    print(&mut buffer, &records).unwrap();
+
+    let parsed: TomlRecords = parse(Cursor::new(buffer)).unwrap();
+
+    assert_eq!(
+        parsed.list(),
+        &vec![sample_csv_record(), sample_csv_record()]
+    );
+}
+
+#[test]
+fn toml_parse_fails_on_unknown_table() {
+    let data = Cursor::new("[[transfer]]\nfrom_client = \"Alice\"\n");
+
+    let err = parse::<TomlRecords>(data).err().unwrap();
+
+    assert!(err.to_string().contains("transfer"), "{err}");
+}
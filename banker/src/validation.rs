@@ -0,0 +1,350 @@
+//! Модуль содержит проверки банковских реквизитов: IBAN, BIC, российских БИК
+//! и 20-значных счетов с контрольным ключом.
+//!
+//! Поля `from_bank` и `to_bank` проверяются функцией [crate::validate]: значение
+//! распознаётся как IBAN, BIC, БИК или пара `БИК/счёт` и проверяется соответствующей
+//! функцией этого модуля.
+
+use crate::records::CsvRecord;
+
+use std::error::Error;
+use std::fmt::Display;
+
+/// Длины IBAN по странам из реестра SWIFT.
+const IBAN_LENGTHS: [(&str, usize); 89] = [
+    ("AD", 24),
+    ("AE", 23),
+    ("AL", 28),
+    ("AT", 20),
+    ("AZ", 28),
+    ("BA", 20),
+    ("BE", 16),
+    ("BG", 22),
+    ("BH", 22),
+    ("BI", 27),
+    ("BR", 29),
+    ("BY", 28),
+    ("CH", 21),
+    ("CR", 22),
+    ("CY", 28),
+    ("CZ", 24),
+    ("DE", 22),
+    ("DJ", 27),
+    ("DK", 18),
+    ("DO", 28),
+    ("EE", 20),
+    ("EG", 29),
+    ("ES", 24),
+    ("FI", 18),
+    ("FK", 18),
+    ("FO", 18),
+    ("FR", 27),
+    ("GB", 22),
+    ("GE", 22),
+    ("GI", 23),
+    ("GL", 18),
+    ("GR", 27),
+    ("GT", 28),
+    ("HN", 28),
+    ("HR", 21),
+    ("HU", 28),
+    ("IE", 22),
+    ("IL", 23),
+    ("IQ", 23),
+    ("IS", 26),
+    ("IT", 27),
+    ("JO", 30),
+    ("KW", 30),
+    ("KZ", 20),
+    ("LB", 28),
+    ("LC", 32),
+    ("LI", 21),
+    ("LT", 20),
+    ("LU", 20),
+    ("LV", 21),
+    ("LY", 25),
+    ("MC", 27),
+    ("MD", 24),
+    ("ME", 22),
+    ("MK", 19),
+    ("MN", 20),
+    ("MR", 27),
+    ("MT", 31),
+    ("MU", 30),
+    ("NI", 28),
+    ("NL", 18),
+    ("NO", 15),
+    ("OM", 23),
+    ("PK", 24),
+    ("PL", 28),
+    ("PS", 29),
+    ("PT", 25),
+    ("QA", 29),
+    ("RO", 24),
+    ("RS", 22),
+    ("RU", 33),
+    ("SA", 24),
+    ("SC", 31),
+    ("SD", 18),
+    ("SE", 24),
+    ("SI", 19),
+    ("SK", 24),
+    ("SM", 27),
+    ("SO", 23),
+    ("ST", 25),
+    ("SV", 28),
+    ("TL", 23),
+    ("TN", 24),
+    ("TR", 26),
+    ("UA", 29),
+    ("VA", 22),
+    ("VG", 24),
+    ("XK", 20),
+    ("YE", 30),
+];
+
+/// Веса для контрольного ключа российского счёта.
+const ACCOUNT_WEIGHTS: [u32; 3] = [7, 1, 3];
+
+/// Ошибка проверки реквизита.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// Значение не похоже ни на один из поддерживаемых реквизитов.
+    UnknownFormat,
+    /// Недопустимые символы.
+    InvalidCharacters,
+    /// Страна, для которой не известна длина IBAN.
+    UnknownCountry(String),
+    /// Неверная длина.
+    InvalidLength { expected: usize, actual: usize },
+    /// Нарушена структура реквизита, в сообщении указано, что именно.
+    InvalidStructure(&'static str),
+    /// Не сошлась контрольная сумма.
+    InvalidChecksum,
+}
+
+/// Найденная проблема в банковских реквизитах операции.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    /// Номер операции в списке, начиная с 0.
+    pub index: usize,
+    /// Номер транзакции.
+    pub transaction: String,
+    /// Проверенное поле, `from_bank` или `to_bank`.
+    pub field: &'static str,
+    /// Проверенное значение.
+    pub value: String,
+    pub error: ValidationError,
+}
+
+/// Проверяет IBAN: длину для страны и контрольную сумму mod-97.
+/// Пробелы между группами символов допускаются.
+pub fn iban(value: &str) -> Result<(), ValidationError> {
+    let iban: String = value
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_uppercase();
+    if !iban.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(ValidationError::InvalidCharacters);
+    }
+    if iban.len() < 4 {
+        return Err(ValidationError::InvalidStructure(
+            "IBAN начинается с кода страны и двух контрольных цифр",
+        ));
+    }
+
+    let country = &iban[..2];
+    let expected = IBAN_LENGTHS
+        .iter()
+        .find(|(code, _)| *code == country)
+        .map(|(_, length)| *length)
+        .ok_or_else(|| ValidationError::UnknownCountry(country.into()))?;
+    if iban.len() != expected {
+        return Err(ValidationError::InvalidLength {
+            expected,
+            actual: iban.len(),
+        });
+    }
+    if !iban[2..4].chars().all(|c| c.is_ascii_digit()) {
+        return Err(ValidationError::InvalidStructure(
+            "после кода страны должны идти две контрольные цифры",
+        ));
+    }
+
+    // Первые четыре символа переносятся в конец, буквы заменяются числами A = 10 ... Z = 35.
+    let remainder = iban[4..]
+        .chars()
+        .chain(iban[..4].chars())
+        .fold(0, |remainder, c| {
+            let digit = c.to_digit(36).unwrap_or_default();
+            let shift = if digit < 10 { 10 } else { 100 };
+            (remainder * shift + digit) % 97
+        });
+    if remainder != 1 {
+        return Err(ValidationError::InvalidChecksum);
+    }
+
+    Ok(())
+}
+
+/// Проверяет структуру BIC (SWIFT кода): 4 буквы банка, 2 буквы страны,
+/// 2 символа местоположения и необязательные 3 символа филиала.
+pub fn bic(value: &str) -> Result<(), ValidationError> {
+    if !value.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(ValidationError::InvalidCharacters);
+    }
+    if value.len() != 8 && value.len() != 11 {
+        return Err(ValidationError::InvalidLength {
+            expected: if value.len() < 8 { 8 } else { 11 },
+            actual: value.len(),
+        });
+    }
+    if !value[..4].chars().all(|c| c.is_ascii_uppercase()) {
+        return Err(ValidationError::InvalidStructure(
+            "код банка должен состоять из 4 заглавных букв",
+        ));
+    }
+    if !value[4..6].chars().all(|c| c.is_ascii_uppercase()) {
+        return Err(ValidationError::InvalidStructure(
+            "код страны должен состоять из 2 заглавных букв",
+        ));
+    }
+    if value[6..].chars().any(|c| c.is_ascii_lowercase()) {
+        return Err(ValidationError::InvalidStructure(
+            "коды местоположения и филиала пишутся заглавными буквами",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Проверяет российский БИК: 9 цифр, начинается с кода России `04`.
+pub fn bik(value: &str) -> Result<(), ValidationError> {
+    if !value.chars().all(|c| c.is_ascii_digit()) {
+        return Err(ValidationError::InvalidCharacters);
+    }
+    if value.len() != 9 {
+        return Err(ValidationError::InvalidLength {
+            expected: 9,
+            actual: value.len(),
+        });
+    }
+    if !value.starts_with("04") {
+        return Err(ValidationError::InvalidStructure(
+            "БИК должен начинаться с кода России 04",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Проверяет 20-значный российский счёт и его контрольный ключ по БИК банка.
+///
+/// Для счетов в кредитных организациях ключ считается по трём последним цифрам БИК,
+/// для корреспондентских счетов `301…` по условному номеру РКЦ: `0` и пятая-шестая цифры БИК.
+pub fn ru_account(bik_value: &str, account: &str) -> Result<(), ValidationError> {
+    bik(bik_value)?;
+    if !account.chars().all(|c| c.is_ascii_digit()) {
+        return Err(ValidationError::InvalidCharacters);
+    }
+    if account.len() != 20 {
+        return Err(ValidationError::InvalidLength {
+            expected: 20,
+            actual: account.len(),
+        });
+    }
+
+    let prefix = if account.starts_with("301") {
+        format!("0{}", &bik_value[4..6])
+    } else {
+        bik_value[6..].to_string()
+    };
+    let sum: u32 = prefix
+        .chars()
+        .chain(account.chars())
+        .zip(ACCOUNT_WEIGHTS.iter().cycle())
+        .map(|(c, weight)| c.to_digit(10).unwrap_or_default() * weight % 10)
+        .sum();
+    if !sum.is_multiple_of(10) {
+        return Err(ValidationError::InvalidChecksum);
+    }
+
+    Ok(())
+}
+
+/// Распознаёт реквизит банка и проверяет его: пара `БИК/счёт`, БИК, IBAN или BIC.
+pub fn bank(value: &str) -> Result<(), ValidationError> {
+    let value = value.trim();
+    let starts_with_letters = |n: usize| {
+        value
+            .get(..n)
+            .is_some_and(|prefix| prefix.chars().all(|c| c.is_ascii_alphabetic()))
+    };
+
+    if let Some((bik_value, account)) = value.split_once('/') {
+        ru_account(bik_value.trim(), account.trim())
+    } else if value.chars().all(|c| c.is_ascii_digit()) && !value.is_empty() {
+        bik(value)
+    } else if starts_with_letters(2) && value[2..].starts_with(|c: char| c.is_ascii_digit()) {
+        iban(value)
+    } else if starts_with_letters(6) && (value.len() == 8 || value.len() == 11) {
+        bic(value)
+    } else {
+        Err(ValidationError::UnknownFormat)
+    }
+}
+
+/// Проверяет реквизиты банков отправителя и получателя у всех операций.
+pub fn records(records: &[CsvRecord]) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    for (index, record) in records.iter().enumerate() {
+        for (field, value) in [
+            ("from_bank", &record.from_bank),
+            ("to_bank", &record.to_bank),
+        ] {
+            if let Err(error) = bank(value) {
+                issues.push(Issue {
+                    index,
+                    transaction: record.transaction.clone(),
+                    field,
+                    value: value.clone(),
+                    error,
+                });
+            }
+        }
+    }
+    issues
+}
+
+impl Error for ValidationError {}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use ValidationError::*;
+        match self {
+            UnknownFormat => write!(f, "не похоже ни на IBAN, ни на BIC, ни на БИК"),
+            InvalidCharacters => write!(f, "недопустимые символы"),
+            UnknownCountry(country) => write!(f, "неизвестная для IBAN страна `{country}`"),
+            InvalidLength { expected, actual } => {
+                write!(f, "неверная длина: ожидалось {expected}, получено {actual}")
+            }
+            InvalidStructure(err) => write!(f, "{err}"),
+            InvalidChecksum => write!(f, "не сходится контрольная сумма"),
+        }
+    }
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "операция `{}` (№{}), {} `{}`: {}",
+            self.transaction,
+            self.index + 1,
+            self.field,
+            self.value,
+            self.error
+        )
+    }
+}
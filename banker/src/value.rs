@@ -0,0 +1,87 @@
+//! Модуль приводит поля операций к их типам, чтобы суммы сравнивались как числа,
+//! а даты как даты. Используется правилами и фильтрами.
+
+use crate::records::{CsvRecord, DATE_FORMAT, parse_date};
+
+use chrono::NaiveDate;
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+/// Тип поля операции.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Kind {
+    Number,
+    Date,
+    Text,
+}
+
+/// Значение поля, приведённое к типу поля.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    Number(f64),
+    Date(NaiveDate),
+    Text(String),
+}
+
+/// Возвращает тип поля: `amount` число, `date` дата, остальные поля строки.
+pub(crate) fn kind(field: &str) -> Kind {
+    match field {
+        "amount" => Kind::Number,
+        "date" => Kind::Date,
+        _ => Kind::Text,
+    }
+}
+
+/// Возвращает значение поля операции, ошибка для некорректной даты.
+pub(crate) fn record_value(record: &CsvRecord, field: &str) -> Result<Value, String> {
+    match kind(field) {
+        Kind::Number => Ok(Value::Number(record.amount)),
+        Kind::Date => parse_date(&record.date)
+            .map(Value::Date)
+            .map_err(|err| format!("некорректная дата `{}`: {err}", record.date)),
+        Kind::Text => Ok(Value::Text(record.field(field).unwrap_or_default())),
+    }
+}
+
+impl Value {
+    pub(crate) fn kind(&self) -> Kind {
+        match self {
+            Value::Number(_) => Kind::Number,
+            Value::Date(_) => Kind::Date,
+            Value::Text(_) => Kind::Text,
+        }
+    }
+}
+
+/// Сравниваются только значения одного типа.
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Value::Number(a), Value::Number(b)) => a.partial_cmp(b),
+            (Value::Date(a), Value::Date(b)) => a.partial_cmp(b),
+            (Value::Text(a), Value::Text(b)) => a.partial_cmp(b),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Kind::Number => write!(f, "число"),
+            Kind::Date => write!(f, "дата"),
+            Kind::Text => write!(f, "строка"),
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Number(value) => write!(f, "{value}"),
+            Value::Date(value) => write!(f, "{}", value.format(DATE_FORMAT)),
+            Value::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use clap::{Parser, ValueEnum};
+use banker::generate::Amounts;
+use clap::{Args, Parser, Subcommand, ValueEnum};
 
 use std::path::PathBuf;
 
@@ -6,8 +7,67 @@ use std::path::PathBuf;
 pub enum Format {
     /// csv
     Csv,
+    /// csv с колонками debit и credit вместо amount (для записи нужен --our-client)
+    DebitCredit,
     /// json
     Json,
+    /// xml
+    Xml,
+    /// yaml, последовательность отображений
+    Yaml,
+    /// toml, массив таблиц [[transaction]]
+    Toml,
+    /// строки с фиксированной шириной колонок (нужен --layout)
+    Fixed,
+    /// ledger/hledger журнал (только запись)
+    Ledger,
+    /// beancount журнал (только запись)
+    Beancount,
+    /// таблица для терминала (только запись)
+    Table,
+    /// таблица markdown (только запись)
+    Markdown,
+    /// html отчёт с итогами по банкам (только запись)
+    Html,
+    /// parquet
+    #[cfg(feature = "columnar")]
+    Parquet,
+    /// arrow ipc
+    #[cfg(feature = "columnar")]
+    Arrow,
+    /// messagepack
+    #[cfg(feature = "msgpack")]
+    Msgpack,
+    /// cbor
+    #[cfg(feature = "cbor")]
+    Cbor,
+    /// база sqlite (нужны пути --input или --output)
+    #[cfg(feature = "sqlite")]
+    Sqlite,
+}
+
+#[cfg(feature = "sqlite")]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum SqliteMode {
+    /// пересоздать таблицу
+    Replace,
+    /// дописать к существующим операциям
+    Append,
+}
+
+#[cfg(feature = "columnar")]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum Compression {
+    /// без сжатия
+    None,
+    /// snappy (только parquet)
+    Snappy,
+    /// gzip (только parquet)
+    Gzip,
+    /// lz4
+    Lz4,
+    /// zstd
+    Zstd,
 }
 
 #[derive(Parser)]
@@ -16,21 +76,347 @@ pub enum Format {
 #[command(after_help = "Alternative usage: bconv [OPTIONS] <samples/data.csv\n\
     Note: --input flag has priority over stdout")]
 pub struct Cli {
-    /// Путь к исходному файлу
-    #[arg(short, long, value_name = "FILE")]
-    pub input: Option<PathBuf>,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Путь к исходному файлу, каталогу или шаблону вроде 'statements/*.csv'.
+    /// Можно указать несколько раз, операции из всех файлов объединяются
+    #[arg(short, long, global = true, value_name = "FILE")]
+    pub input: Vec<PathBuf>,
 
     /// Формат исходного содержимого
-    #[arg(value_enum, long)]
+    #[arg(value_enum, long, global = true)]
     pub in_format: Option<Format>,
 
     /// Формат результата
-    #[arg(value_enum, long)]
+    #[arg(value_enum, long, global = true)]
     pub out_format: Option<Format>,
 
     /// Путь к файлу для сохранения результата
-    #[arg(short, long, value_name = "FILE")]
+    #[arg(short, long, global = true, value_name = "FILE")]
     pub output: Option<PathBuf>,
+
+    /// Описание колонок для формата fixed в toml
+    #[arg(long, global = true, value_name = "FILE")]
+    pub layout: Option<PathBuf>,
+
+    /// Шаблон счёта отправителя для ledger и beancount, например 'Assets:{bank}:{client}'
+    #[arg(long, global = true, value_name = "ACCOUNT")]
+    pub from_account: Option<String>,
+
+    /// Шаблон счёта получателя для ledger и beancount
+    #[arg(long, global = true, value_name = "ACCOUNT")]
+    pub to_account: Option<String>,
+
+    /// Валюта для ledger и beancount, если суммы не пересчитаны через --convert-currency
+    #[arg(long, global = true)]
+    pub commodity: Option<String>,
+
+    /// Проверить реквизиты банков (IBAN, BIC, БИК или БИК/счёт) и не записывать результат при ошибках
+    #[arg(long, global = true)]
+    pub validate: bool,
+
+    /// Оставить только операции, подходящие под выражение, например
+    /// `amount > 10000 && from_bank == "bank_a" && date >= 2025-01-01`
+    #[arg(long, global = true, value_name = "EXPR")]
+    pub filter: Option<String>,
+
+    /// Разложить операции по категориям по правилам из toml, категория пишется в csv
+    #[arg(long, global = true, value_name = "FILE")]
+    pub categorize: Option<PathBuf>,
+
+    /// Пересчитать суммы в валюту, например RUB, по таблице курсов из --rates;
+    /// курс записывается в csv, ledger и beancount
+    #[arg(
+        long,
+        global = true,
+        value_name = "CURRENCY",
+        requires_all = ["rates", "currency"]
+    )]
+    pub convert_currency: Option<String>,
+
+    /// Валюта сумм в исходных файлах
+    #[arg(
+        long,
+        global = true,
+        value_name = "CURRENCY",
+        requires = "convert_currency"
+    )]
+    pub currency: Option<String>,
+
+    /// Таблица курсов в csv с колонками date,from,to,rate
+    #[arg(
+        long,
+        global = true,
+        value_name = "FILE",
+        requires = "convert_currency"
+    )]
+    pub rates: Option<PathBuf>,
+
+    /// Если курса на дату операции нет, искать его не дальше стольких дней назад
+    #[arg(long, global = true, value_name = "N", default_value_t = 7)]
+    pub rate_fallback_days: u64,
+
+    /// При нескольких исходных файлах упорядочить операции по дате,
+    /// операции с одной датой остаются в порядке файлов
+    #[arg(long, global = true)]
+    pub merge_by_date: bool,
+
+    /// Добавить в csv колонки source_file и source_row с файлом и номером операции в нём
+    #[arg(long, global = true)]
+    pub provenance: bool,
+
+    /// Разложить операции по файлам по шаблону пути, например
+    /// 'out/{to_bank}/{date:%Y-%m}.csv'; {part} — номер части при --max-records
+    #[arg(
+        long,
+        global = true,
+        value_name = "TEMPLATE",
+        conflicts_with = "output"
+    )]
+    pub split_by: Option<String>,
+
+    /// Не больше стольких операций в одном файле при --split-by
+    #[arg(long, global = true, value_name = "N", requires = "split_by")]
+    pub max_records: Option<usize>,
+
+    /// Отсортировать операции по полям через запятую, `-` перед полем для убывания,
+    /// например `date,-amount`
+    #[arg(long, global = true, value_name = "FIELDS", allow_hyphen_values = true)]
+    pub sort: Option<String>,
+
+    /// Записать только эти колонки в этом порядке (для csv и table),
+    /// например `date,amount,to_client`
+    #[arg(long, global = true, value_name = "FIELDS")]
+    pub columns: Option<String>,
+
+    /// Наш клиент, относительно которого операции делятся на списания и поступления
+    #[arg(long, global = true, value_name = "CLIENT")]
+    pub our_client: Option<String>,
+
+    /// Банк нашего клиента, если у него счета в нескольких банках
+    #[arg(long, global = true, value_name = "BANK", requires = "our_client")]
+    pub our_bank: Option<String>,
+
+    /// Записать суммы со знаком: поступления положительные, списания отрицательные
+    #[arg(long, global = true, requires = "our_client")]
+    pub signed: bool,
+
+    /// Обезличить операции: псевдонимы клиентов по ключу из --key-file, в счетах
+    /// видны только последние 4 символа
+    #[arg(long, global = true, requires = "key_file")]
+    pub anonymize: bool,
+
+    /// Секретный ключ для псевдонимов, один и тот же ключ даёт одни и те же псевдонимы
+    #[arg(long, global = true, value_name = "FILE", requires = "anonymize")]
+    pub key_file: Option<PathBuf>,
+
+    /// Сдвинуть суммы на случайную долю не больше указанной, например 0.05
+    #[arg(long, global = true, value_name = "RATIO", requires = "anonymize")]
+    pub jitter_amount: Option<f64>,
+
+    /// Сдвинуть даты не больше чем на N дней в обе стороны, N не больше 3650
+    #[arg(long, global = true, value_name = "N", requires = "anonymize")]
+    pub jitter_days: Option<u64>,
+
+    #[command(flatten)]
+    pub xml: XmlArgs,
+
+    /// Количество строк в группе строк parquet или в батче arrow
+    #[cfg(feature = "columnar")]
+    #[arg(long, global = true, value_name = "ROWS")]
+    pub row_group_size: Option<usize>,
+
+    /// Сжатие для parquet и arrow
+    #[cfg(feature = "columnar")]
+    #[arg(value_enum, long, global = true)]
+    pub compression: Option<Compression>,
+
+    /// Что делать с операциями, уже сохранёнными в sqlite
+    #[cfg(feature = "sqlite")]
+    #[arg(value_enum, long, global = true, default_value = "replace")]
+    pub sqlite_mode: SqliteMode,
+
+    /// Условие WHERE для чтения из sqlite, например "amount > 1000"
+    #[cfg(feature = "sqlite")]
+    #[arg(long = "where", global = true, value_name = "SQL")]
+    pub sqlite_where: Option<String>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Печатает схему, по которой партнёры могут проверить результат конвертации
+    Schema {
+        /// Формат, для которого нужна схема
+        #[arg(value_enum, long)]
+        format: SchemaFormat,
+    },
+    /// Проверяет операции по бизнес-правилам, при ошибках завершается с ненулевым кодом
+    Validate {
+        /// Правила в toml
+        #[arg(long, value_name = "FILE")]
+        rules: PathBuf,
+
+        /// Формат отчёта о нарушениях
+        #[arg(value_enum, long, default_value = "text")]
+        report: ReportFormat,
+    },
+    /// Удаляет повторяющиеся операции и записывает результат как обычная конвертация
+    Dedupe {
+        /// Поля, по которым операции считаются одинаковыми
+        #[arg(long, value_delimiter = ',', default_value = "transaction")]
+        keys: Vec<String>,
+
+        /// Считать дубликатами операции с одинаковыми ключами, если их даты отличаются
+        /// не больше чем на N дней (date не должно быть среди ключей)
+        #[arg(long, value_name = "N")]
+        window_days: Option<u64>,
+
+        /// Какую операцию из группы дубликатов оставить
+        #[arg(value_enum, long, default_value = "first")]
+        keep: Keep,
+    },
+    /// Сравнивает две выписки и печатает добавленные, удалённые и изменённые операции
+    Diff {
+        /// Старая выписка
+        old: PathBuf,
+
+        /// Новая выписка, формат может отличаться от старой
+        new: PathBuf,
+
+        /// Поля, по которым сопоставляются операции
+        #[arg(long, value_delimiter = ',', default_value = "transaction")]
+        key: Vec<String>,
+
+        /// Формат отчёта о различиях
+        #[arg(value_enum, long, default_value = "text")]
+        report: DiffFormat,
+    },
+    /// Сверяет банковскую выписку с нашим учётом: по номеру транзакции, затем по допускам
+    Reconcile {
+        /// Банковская выписка
+        statement: PathBuf,
+
+        /// Ожидаемые платежи из нашего учёта
+        ledger: PathBuf,
+
+        /// Допустимая разница сумм
+        #[arg(long, value_name = "AMOUNT", default_value_t = 0.0)]
+        amount_epsilon: f64,
+
+        /// Допустимая разница дат в днях
+        #[arg(long, value_name = "N", default_value_t = 0)]
+        date_days: u64,
+
+        /// Наименьшая похожесть имён клиентов от 0 до 1
+        #[arg(long, value_name = "RATIO", default_value_t = 0.8)]
+        name_similarity: f64,
+
+        /// Формат отчёта о сверке
+        #[arg(value_enum, long, default_value = "text")]
+        report: ReconcileFormat,
+    },
+    /// Генерирует правдоподобные операции для нагрузочных тестов и записывает
+    /// их как обычная конвертация, по умолчанию в csv
+    Generate {
+        /// Сколько операций сгенерировать
+        #[arg(long, default_value_t = 100)]
+        count: usize,
+
+        /// Начальное значение генератора, с тем же значением операции повторяются
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Сколько разных клиентов участвуют в операциях
+        #[arg(long, value_name = "N", default_value_t = 100)]
+        clients: usize,
+
+        /// Распределение сумм: uniform:MIN:MAX или lognormal:MEDIAN:SIGMA
+        #[arg(long, value_name = "DIST", default_value = "lognormal:5000:1")]
+        amounts: Amounts,
+
+        /// Первая дата операций, например 01-01-2025
+        #[arg(long, value_name = "DATE", default_value = "01-01-2025")]
+        from: String,
+
+        /// Последняя дата операций включительно
+        #[arg(long, value_name = "DATE", default_value = "31-12-2025")]
+        to: String,
+    },
+    /// Считает количество, сумму, минимум, максимум и среднее по группам операций
+    Stats {
+        /// Поля, по которым группируются операции, например `from_bank,to_bank`.
+        /// Без полей печатаются итоги по всем операциям
+        #[arg(long, value_delimiter = ',')]
+        group_by: Vec<String>,
+
+        /// Формат итогов
+        #[arg(value_enum, long, default_value = "table")]
+        report: StatsFormat,
+    },
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum Keep {
+    /// первую
+    First,
+    /// последнюю
+    Last,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum ReportFormat {
+    /// текст, по строке на нарушение
+    Text,
+    /// json
+    Json,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum DiffFormat {
+    /// текст, по строке на добавленную, удалённую или изменённую операцию
+    Text,
+    /// json
+    Json,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum ReconcileFormat {
+    /// текст: итоги по видам совпадений и операции, сопоставленные не по номеру или без пары
+    Text,
+    /// json
+    Json,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum StatsFormat {
+    /// текстовая таблица
+    Table,
+    /// csv
+    Csv,
+    /// json
+    Json,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum SchemaFormat {
+    /// xsd для xml
+    Xml,
+}
+
+#[derive(Args)]
+pub struct XmlArgs {
+    /// Корневой элемент xml
+    #[arg(long, global = true, value_name = "NAME")]
+    pub xml_root: Option<String>,
+
+    /// Элемент одной операции в xml
+    #[arg(long, global = true, value_name = "NAME")]
+    pub xml_record: Option<String>,
+
+    /// Название элемента для поля операции в xml, например 'amount=Sum'
+    #[arg(long, global = true, value_name = "FIELD=NAME")]
+    pub xml_element: Vec<String>,
 }
 
 pub fn parse() -> Cli {
@@ -1,14 +1,60 @@
 use crate::cli::{self, Cli};
+use banker::anonymize::{AnonymizeOptions, Anonymizer};
+use banker::categorize::Categories;
+use banker::dedupe::{DedupeOptions, Keep};
+use banker::direction::Party;
+use banker::filter::{Filter, FilterError};
+use banker::fx::RateTable;
+use banker::generate::GenerateOptions;
+#[cfg(feature = "sqlite")]
+use banker::records::SqliteMode;
+#[cfg(feature = "columnar")]
+use banker::records::{ArrowOptions, Compression, ParquetOptions};
+use banker::records::{Columns, FixedLayout, JournalOptions, TableOptions, XmlOptions};
+use banker::sort::SortKey;
+use banker::split::Template;
 use std::error::Error;
 use std::ffi::OsStr;
 use std::fmt::Display;
-use std::io::{IsTerminal, stdin};
-use std::path::PathBuf;
+use std::fs;
+use std::io::{IsTerminal, stdin, stdout};
+use std::path::{Path, PathBuf};
+use terminal_size::{Width, terminal_size};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Format {
     Csv,
+    DebitCredit,
     Json,
+    Xml,
+    Yaml,
+    Toml,
+    Fixed,
+    Ledger,
+    Beancount,
+    Table,
+    Markdown,
+    Html,
+    #[cfg(feature = "columnar")]
+    Parquet,
+    #[cfg(feature = "columnar")]
+    Arrow,
+    #[cfg(feature = "msgpack")]
+    Msgpack,
+    #[cfg(feature = "cbor")]
+    Cbor,
+    #[cfg(feature = "sqlite")]
+    Sqlite,
+}
+
+impl Format {
+    /// Журналы бухгалтерии и отчёты умеем только записывать.
+    pub fn is_readable(&self) -> bool {
+        !matches!(
+            self,
+            Format::Ledger | Format::Beancount | Format::Table | Format::Markdown | Format::Html
+        )
+    }
 }
 
 impl From<cli::Format> for Format {
@@ -16,7 +62,60 @@ impl From<cli::Format> for Format {
         use cli::Format::*;
         match cli_format {
             Csv => Format::Csv,
+            DebitCredit => Format::DebitCredit,
             Json => Format::Json,
+            Xml => Format::Xml,
+            Yaml => Format::Yaml,
+            Toml => Format::Toml,
+            Fixed => Format::Fixed,
+            Ledger => Format::Ledger,
+            Beancount => Format::Beancount,
+            Table => Format::Table,
+            Markdown => Format::Markdown,
+            Html => Format::Html,
+            #[cfg(feature = "columnar")]
+            Parquet => Format::Parquet,
+            #[cfg(feature = "columnar")]
+            Arrow => Format::Arrow,
+            #[cfg(feature = "msgpack")]
+            Msgpack => Format::Msgpack,
+            #[cfg(feature = "cbor")]
+            Cbor => Format::Cbor,
+            #[cfg(feature = "sqlite")]
+            Sqlite => Format::Sqlite,
+        }
+    }
+}
+
+impl From<cli::Keep> for Keep {
+    fn from(cli_keep: cli::Keep) -> Self {
+        match cli_keep {
+            cli::Keep::First => Keep::First,
+            cli::Keep::Last => Keep::Last,
+        }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<cli::SqliteMode> for SqliteMode {
+    fn from(cli_mode: cli::SqliteMode) -> Self {
+        match cli_mode {
+            cli::SqliteMode::Replace => SqliteMode::Replace,
+            cli::SqliteMode::Append => SqliteMode::Append,
+        }
+    }
+}
+
+#[cfg(feature = "columnar")]
+impl From<cli::Compression> for Compression {
+    fn from(cli_compression: cli::Compression) -> Self {
+        use cli::Compression::*;
+        match cli_compression {
+            None => Compression::None,
+            Snappy => Compression::Snappy,
+            Gzip => Compression::Gzip,
+            Lz4 => Compression::Lz4,
+            Zstd => Compression::Zstd,
         }
     }
 }
@@ -26,23 +125,122 @@ impl Display for Format {
         use Format::*;
         match self {
             Csv => write!(f, "csv"),
+            DebitCredit => write!(f, "debit-credit"),
             Json => write!(f, "json"),
+            Xml => write!(f, "xml"),
+            Yaml => write!(f, "yaml"),
+            Toml => write!(f, "toml"),
+            Fixed => write!(f, "fixed"),
+            Ledger => write!(f, "ledger"),
+            Beancount => write!(f, "beancount"),
+            Table => write!(f, "table"),
+            Markdown => write!(f, "markdown"),
+            Html => write!(f, "html"),
+            #[cfg(feature = "columnar")]
+            Parquet => write!(f, "parquet"),
+            #[cfg(feature = "columnar")]
+            Arrow => write!(f, "arrow"),
+            #[cfg(feature = "msgpack")]
+            Msgpack => write!(f, "msgpack"),
+            #[cfg(feature = "cbor")]
+            Cbor => write!(f, "cbor"),
+            #[cfg(feature = "sqlite")]
+            Sqlite => write!(f, "sqlite"),
+        }
+    }
+}
+
+/// Исходный файл и его формат.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub path: PathBuf,
+    pub format: Format,
+}
+
+/// Откуда берутся операции.
+#[derive(Debug)]
+pub enum Origin {
+    /// Исходные файлы, без них операции читаются из stdin в формате `in_format`.
+    Inputs(Vec<Input>),
+    /// Операции создаются генератором, см. команду generate.
+    Generate(GenerateOptions),
+}
+
+impl Origin {
+    /// Исходные файлы, у сгенерированных операций их нет.
+    pub fn inputs(&self) -> &[Input] {
+        match self {
+            Origin::Inputs(inputs) => inputs,
+            Origin::Generate(_) => &[],
         }
     }
 }
 
+/// Пересчёт сумм в другую валюту.
+#[derive(Debug)]
+pub struct FxOptions {
+    pub rates: RateTable,
+    pub from: String,
+    pub to: String,
+    pub fallback_days: u64,
+}
+
 #[derive(Debug)]
 pub struct Config {
-    pub input_path: Option<PathBuf>,
+    pub origin: Origin,
     pub output_path: Option<PathBuf>,
     pub in_format: Format,
     pub out_format: Format,
+    pub journal_options: JournalOptions,
+    pub table_options: TableOptions,
+    pub xml_options: XmlOptions,
+    pub layout: Option<FixedLayout>,
+    pub validate: bool,
+    pub filter: Option<Filter>,
+    pub fx: Option<FxOptions>,
+    pub categories: Option<Categories>,
+    pub sort: Option<Vec<SortKey>>,
+    pub columns: Columns,
+    pub dedupe: Option<(DedupeOptions, Keep)>,
+    pub party: Option<Party>,
+    pub signed: bool,
+    pub anonymizer: Option<Anonymizer>,
+    pub merge_by_date: bool,
+    pub provenance: bool,
+    /// Шаблон путей и наибольшее число операций в файле.
+    pub split: Option<(Template, Option<usize>)>,
+    #[cfg(feature = "columnar")]
+    pub parquet_options: ParquetOptions,
+    #[cfg(feature = "columnar")]
+    pub arrow_options: ArrowOptions,
+    #[cfg(feature = "sqlite")]
+    pub sqlite_mode: SqliteMode,
+    #[cfg(feature = "sqlite")]
+    pub sqlite_where: Option<String>,
 }
 
 #[derive(Debug)]
 pub enum ConfigError {
     InFormatUndefined,
+    InputFormatUndefined(PathBuf),
+    InFormatNotReadable(Format),
     InputRequired,
+    InvalidInput(String),
+    InvalidXmlOptions(String),
+    LayoutRequired,
+    InvalidLayout(String),
+    PartyRequired,
+    InvalidFilter(FilterError),
+    InvalidRates(String),
+    InvalidCategories(String),
+    InvalidAnonymize(String),
+    InvalidSort(String),
+    InvalidColumns(String),
+    InvalidSplit(String),
+    ColumnsNotSupported(Format),
+    ProvenanceNotSupported(Format),
+    #[cfg(feature = "sqlite")]
+    PathRequired(Format),
 }
 
 impl Error for ConfigError {}
@@ -52,7 +250,36 @@ impl Display for ConfigError {
         use ConfigError::*;
         match self {
             InFormatUndefined => write!(f, "не получилось определить формат :("),
+            InFormatNotReadable(format) => write!(f, "формат '{format}' не умею читать"),
+            InputFormatUndefined(path) => write!(
+                f,
+                "не получилось определить формат '{}' :(",
+                path.to_string_lossy()
+            ),
             InputRequired => write!(f, "отсутствуют входящие данные"),
+            InvalidInput(err) => write!(f, "некорректный исходный файл: {err}"),
+            InvalidXmlOptions(err) => write!(f, "некорректные названия элементов xml: {err}"),
+            LayoutRequired => write!(f, "для формата 'fixed' нужно описание колонок"),
+            InvalidLayout(err) => write!(f, "некорректное описание колонок: {err}"),
+            PartyRequired => write!(f, "для формата 'debit-credit' нужна наша сторона"),
+            InvalidFilter(err) => write!(f, "{err}"),
+            InvalidRates(err) => write!(f, "некорректная таблица курсов: {err}"),
+            InvalidCategories(err) => write!(f, "некорректные правила категорий: {err}"),
+            InvalidAnonymize(err) => write!(f, "не получилось настроить обезличивание: {err}"),
+            InvalidSort(err) => write!(f, "некорректная сортировка: {err}"),
+            InvalidColumns(err) => write!(f, "некорректные колонки: {err}"),
+            InvalidSplit(err) => write!(f, "некорректный шаблон файлов: {err}"),
+            ColumnsNotSupported(format) => {
+                write!(f, "выбор колонок не поддерживается для формата '{format}'")
+            }
+            ProvenanceNotSupported(format) => {
+                write!(
+                    f,
+                    "источник операций не поддерживается для формата '{format}'"
+                )
+            }
+            #[cfg(feature = "sqlite")]
+            PathRequired(format) => write!(f, "формат '{format}' работает только с файлами"),
         }
     }
 }
@@ -61,32 +288,277 @@ impl TryFrom<Cli> for Config {
     type Error = ConfigError;
 
     fn try_from(cli: Cli) -> Result<Self, Self::Error> {
-        if let None = cli.input {
-            if stdin().is_terminal() {
-                return Err(ConfigError::InputRequired);
-            }
+        if cli.input.is_empty() && stdin().is_terminal() {
+            return Err(ConfigError::InputRequired);
         };
+        Config::new(cli, None)
+    }
+}
 
-        let in_format = cli
-            .in_format
-            .map(|f| f.into())
-            .or_else(|| get_format_from_extension(cli.input.as_ref()))
-            .ok_or(ConfigError::InFormatUndefined)?;
+impl Config {
+    /// Конфиг команды generate: операции создаются генератором, а не читаются.
+    pub fn generate(cli: Cli, options: GenerateOptions) -> Result<Self, ConfigError> {
+        if !cli.input.is_empty() {
+            return Err(ConfigError::InvalidInput(
+                "сгенерированные операции не читаются из файлов".into(),
+            ));
+        }
+        Config::new(cli, Some(options))
+    }
+
+    fn new(cli: Cli, generate: Option<GenerateOptions>) -> Result<Self, ConfigError> {
+        let forced: Option<Format> = cli.in_format.map(|f| f.into());
+        let inputs = expand_inputs(&cli.input, forced.is_some())?
+            .into_iter()
+            .map(|path| {
+                let format = forced
+                    .clone()
+                    .or_else(|| get_format_from_extension(Some(&path)))
+                    .ok_or_else(|| ConfigError::InputFormatUndefined(path.clone()))?;
+                if !format.is_readable() {
+                    return Err(ConfigError::InFormatNotReadable(format));
+                }
+                Ok(Input { path, format })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Сгенерированные операции уже в модели csv, формат нужен только для вывода по умолчанию.
+        let in_format = match inputs.first() {
+            Some(input) => input.format.clone(),
+            None if generate.is_some() => Format::Csv,
+            None => forced.ok_or(ConfigError::InFormatUndefined)?,
+        };
+
+        if !in_format.is_readable() {
+            return Err(ConfigError::InFormatNotReadable(in_format));
+        }
 
         let out_format = cli
             .out_format
             .map(|f| f.into())
             .unwrap_or(in_format.clone());
 
+        #[cfg(feature = "sqlite")]
+        if (in_format == Format::Sqlite && inputs.is_empty())
+            || (out_format == Format::Sqlite && cli.output.is_none() && cli.split_by.is_none())
+        {
+            return Err(ConfigError::PathRequired(Format::Sqlite));
+        }
+
+        let xml_options = xml_options(cli.xml)?;
+
+        let layout = cli.layout.as_ref().map(read_layout).transpose()?;
+        let reads_fixed =
+            in_format == Format::Fixed || inputs.iter().any(|i| i.format == Format::Fixed);
+        if layout.is_none() && (reads_fixed || out_format == Format::Fixed) {
+            return Err(ConfigError::LayoutRequired);
+        }
+
+        let party = cli.our_client.map(|client| Party {
+            client,
+            bank: cli.our_bank,
+        });
+        if party.is_none() && out_format == Format::DebitCredit {
+            return Err(ConfigError::PartyRequired);
+        }
+
+        let filter = cli
+            .filter
+            .as_deref()
+            .map(Filter::parse)
+            .transpose()
+            .map_err(ConfigError::InvalidFilter)?;
+
+        let fx = match (cli.convert_currency, cli.currency, &cli.rates) {
+            (Some(to), Some(from), Some(path)) => Some(FxOptions {
+                rates: read_rates(path)?,
+                from,
+                to,
+                fallback_days: cli.rate_fallback_days,
+            }),
+            _ => None,
+        };
+
+        let categories = cli.categorize.as_ref().map(read_categories).transpose()?;
+
+        let anonymizer = match &cli.key_file {
+            Some(path) if cli.anonymize => {
+                let options = AnonymizeOptions {
+                    amount_jitter: cli.jitter_amount.unwrap_or_default(),
+                    date_jitter_days: cli.jitter_days.unwrap_or_default(),
+                };
+                Some(
+                    Anonymizer::new(read_key(path)?, options)
+                        .map_err(ConfigError::InvalidAnonymize)?,
+                )
+            }
+            _ => None,
+        };
+
+        let sort = cli
+            .sort
+            .as_deref()
+            .map(SortKey::parse_list)
+            .transpose()
+            .map_err(ConfigError::InvalidSort)?;
+
+        let columns = match cli.columns {
+            Some(_) if !matches!(out_format, Format::Csv | Format::Table) => {
+                return Err(ConfigError::ColumnsNotSupported(out_format));
+            }
+            Some(columns) => columns.parse().map_err(ConfigError::InvalidColumns)?,
+            None => Columns::default(),
+        };
+
+        if cli.provenance && out_format != Format::Csv {
+            return Err(ConfigError::ProvenanceNotSupported(out_format));
+        }
+
+        let split = match cli.split_by {
+            Some(template) => {
+                let template: Template = template.parse().map_err(ConfigError::InvalidSplit)?;
+                if cli.max_records == Some(0) {
+                    return Err(ConfigError::InvalidSplit(
+                        "в файле должна быть хотя бы одна операция".into(),
+                    ));
+                }
+                if cli.max_records.is_some() && !template.has_part() {
+                    return Err(ConfigError::InvalidSplit(
+                        "для --max-records в шаблоне нужен номер части {part}".into(),
+                    ));
+                }
+                Some((template, cli.max_records))
+            }
+            None => None,
+        };
+
+        let mut journal_options = JournalOptions::default();
+        if let Some(account) = cli.from_account {
+            journal_options.from_account = account;
+        }
+        if let Some(account) = cli.to_account {
+            journal_options.to_account = account;
+        }
+        if let Some(commodity) = cli.commodity {
+            journal_options.commodity = commodity;
+        }
+
+        // Таблицу в терминале обрезаем по его ширине.
+        let table_options = TableOptions {
+            max_width: match cli.output {
+                None if split.is_none() && stdout().is_terminal() => {
+                    terminal_size().map(|(Width(w), _)| w.into())
+                }
+                _ => None,
+            },
+            columns: columns.clone(),
+        };
+
+        #[cfg(feature = "columnar")]
+        let (parquet_options, arrow_options) = {
+            let mut parquet_options = ParquetOptions::default();
+            let mut arrow_options = ArrowOptions::default();
+            if let Some(rows) = cli.row_group_size {
+                parquet_options.row_group_size = rows;
+                arrow_options.batch_size = rows;
+            }
+            if let Some(compression) = cli.compression {
+                parquet_options.compression = compression.into();
+                arrow_options.compression = compression.into();
+            }
+            (parquet_options, arrow_options)
+        };
+
+        let origin = match generate {
+            Some(options) => Origin::Generate(options),
+            None => Origin::Inputs(inputs),
+        };
+
         Ok(Self {
-            input_path: cli.input,
+            origin,
             output_path: cli.output,
             in_format,
             out_format,
+            journal_options,
+            table_options,
+            xml_options,
+            layout,
+            validate: cli.validate,
+            filter,
+            fx,
+            categories,
+            sort,
+            columns,
+            dedupe: None,
+            party,
+            signed: cli.signed,
+            anonymizer,
+            merge_by_date: cli.merge_by_date,
+            provenance: cli.provenance,
+            split,
+            #[cfg(feature = "columnar")]
+            parquet_options,
+            #[cfg(feature = "columnar")]
+            arrow_options,
+            #[cfg(feature = "sqlite")]
+            sqlite_mode: cli.sqlite_mode.into(),
+            #[cfg(feature = "sqlite")]
+            sqlite_where: cli.sqlite_where,
         })
     }
 }
 
+/// Собирает названия элементов xml из опций командной строки.
+pub fn xml_options(args: cli::XmlArgs) -> Result<XmlOptions, ConfigError> {
+    let mut options = XmlOptions::default();
+    if let Some(root) = args.xml_root {
+        options.root = root;
+    }
+    if let Some(record) = args.xml_record {
+        options.record = record;
+    }
+    for element in args.xml_element {
+        let (field, name) = element.split_once('=').ok_or_else(|| {
+            ConfigError::InvalidXmlOptions(format!("ожидается FIELD=NAME, получено `{element}`"))
+        })?;
+        options.elements.insert(field.into(), name.into());
+    }
+
+    options.validate().map_err(ConfigError::InvalidXmlOptions)?;
+    Ok(options)
+}
+
+fn read_layout(path: &PathBuf) -> Result<FixedLayout, ConfigError> {
+    let layout = fs::read_to_string(path).map_err(|err| {
+        ConfigError::InvalidLayout(format!("'{}': {err}", path.to_string_lossy()))
+    })?;
+    FixedLayout::from_toml(&layout).map_err(|err| ConfigError::InvalidLayout(err.to_string()))
+}
+
+fn read_categories(path: &PathBuf) -> Result<Categories, ConfigError> {
+    let rules = fs::read_to_string(path).map_err(|err| {
+        ConfigError::InvalidCategories(format!("'{}': {err}", path.to_string_lossy()))
+    })?;
+    Categories::from_toml(&rules).map_err(|err| ConfigError::InvalidCategories(err.to_string()))
+}
+
+/// Ключ читается как есть, без перевода строки в конце.
+fn read_key(path: &PathBuf) -> Result<Vec<u8>, ConfigError> {
+    let mut key = fs::read(path).map_err(|err| {
+        ConfigError::InvalidAnonymize(format!("'{}': {err}", path.to_string_lossy()))
+    })?;
+    while key.last().is_some_and(|byte| byte.is_ascii_whitespace()) {
+        key.pop();
+    }
+    Ok(key)
+}
+
+fn read_rates(path: &PathBuf) -> Result<RateTable, ConfigError> {
+    let file = fs::File::open(path)
+        .map_err(|err| ConfigError::InvalidRates(format!("'{}': {err}", path.to_string_lossy())))?;
+    RateTable::from_csv(file).map_err(ConfigError::InvalidRates)
+}
+
 type Extension<'a> = &'a str;
 
 impl TryFrom<Extension<'_>> for Format {
@@ -96,11 +568,76 @@ impl TryFrom<Extension<'_>> for Format {
         match value {
             "csv" => Ok(Format::Csv),
             "json" => Ok(Format::Json),
+            "xml" => Ok(Format::Xml),
+            "yaml" | "yml" => Ok(Format::Yaml),
+            "toml" => Ok(Format::Toml),
+            "fixed" | "dat" => Ok(Format::Fixed),
+            "ledger" | "journal" | "hledger" => Ok(Format::Ledger),
+            "beancount" | "bean" => Ok(Format::Beancount),
+            "md" | "markdown" => Ok(Format::Markdown),
+            "html" | "htm" => Ok(Format::Html),
+            #[cfg(feature = "columnar")]
+            "parquet" => Ok(Format::Parquet),
+            #[cfg(feature = "columnar")]
+            "arrow" | "feather" => Ok(Format::Arrow),
+            #[cfg(feature = "msgpack")]
+            "msgpack" | "mpk" => Ok(Format::Msgpack),
+            #[cfg(feature = "cbor")]
+            "cbor" => Ok(Format::Cbor),
+            #[cfg(feature = "sqlite")]
+            "db" | "sqlite" | "sqlite3" => Ok(Format::Sqlite),
             _ => Err(()),
         }
     }
 }
 
+/// Раскрывает каталоги и шаблоны в список файлов, каждый в порядке имён.
+/// Из каталога без `--in-format` берутся только файлы с известными читаемыми расширениями.
+fn expand_inputs(paths: &[PathBuf], forced: bool) -> Result<Vec<PathBuf>, ConfigError> {
+    let mut files = Vec::new();
+    for path in paths {
+        let pattern = path.to_string_lossy();
+        if path.is_dir() {
+            let mut entries = fs::read_dir(path)
+                .and_then(|dir| dir.map(|entry| entry.map(|e| e.path())).collect())
+                .map_err(|err| ConfigError::InvalidInput(format!("'{pattern}': {err}")))
+                .map(|entries: Vec<PathBuf>| {
+                    entries
+                        .into_iter()
+                        .filter(|p| p.is_file() && (forced || is_readable_file(p)))
+                        .collect::<Vec<_>>()
+                })?;
+            if entries.is_empty() {
+                return Err(ConfigError::InvalidInput(format!(
+                    "в каталоге '{pattern}' нет подходящих файлов"
+                )));
+            }
+            entries.sort();
+            files.extend(entries);
+        } else if !path.exists() && pattern.contains(['*', '?', '[']) {
+            let mut matched = glob::glob(&pattern)
+                .map_err(|err| ConfigError::InvalidInput(format!("'{pattern}': {err}")))?
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|err| ConfigError::InvalidInput(err.to_string()))?;
+            matched.retain(|p| p.is_file());
+            if matched.is_empty() {
+                return Err(ConfigError::InvalidInput(format!(
+                    "по шаблону '{pattern}' ничего не найдено"
+                )));
+            }
+            matched.sort();
+            files.extend(matched);
+        } else {
+            files.push(path.clone());
+        }
+    }
+    Ok(files)
+}
+
+fn is_readable_file(path: &Path) -> bool {
+    get_format_from_extension(Some(&path.to_path_buf())).is_some_and(|f| f.is_readable())
+}
+
 fn get_format_from_extension(path: Option<&PathBuf>) -> Option<Format> {
     let ext = path?.extension()?;
     OsStr::to_str(ext)?.try_into().ok()
@@ -1,85 +1,508 @@
-use crate::config::Config;
 use crate::config::Format;
+use crate::config::{Config, FxOptions, Input, Origin};
 use crate::error::BconvError;
+use banker::anonymize::Anonymizer;
+use banker::categorize::{self, Categories};
+use banker::dedupe::{self, DedupeOptions, Keep};
+use banker::direction::Party;
 use banker::error::BankError;
-use banker::records::{CsvRecords, JsonRecords};
+use banker::filter::Filter;
+use banker::fx;
+use banker::generate;
+use banker::merge;
+#[cfg(feature = "cbor")]
+use banker::records::CborRecords;
+#[cfg(feature = "msgpack")]
+use banker::records::MsgpackRecords;
+#[cfg(feature = "columnar")]
+use banker::records::{ArrowOptions, ArrowRecords, ParquetOptions, ParquetRecords};
+use banker::records::{
+    BeancountRecords, Columns, CsvRecords, DebitCreditRecords, FixedLayout, FixedRecords,
+    HtmlRecords, JournalOptions, JsonRecords, LedgerRecords, MarkdownRecords, TableOptions,
+    TableRecords, TomlRecords, XmlOptions, XmlRecords, YamlRecords,
+};
 use banker::records::{Parse, Print};
-use std::fs::{File, OpenOptions};
+#[cfg(feature = "sqlite")]
+use banker::records::{SqliteMode, SqliteRecords};
+use banker::sort::{self, SortKey};
+use banker::split::{self, Template};
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, BufReader, BufWriter, Read, Write, stdin, stdout};
+use std::path::{Path, PathBuf};
 
 pub fn convert(cfg: Config) -> Result<(), BconvError> {
-    let reader = get_reader(&cfg).map_err(BconvError::InputError)?;
-    let writer = get_writer(&cfg).map_err(BconvError::OutputError)?;
+    // Применённый курс сохраняют только csv и журналы, в остальных форматах пересчёт
+    // прошёл бы бесследно.
+    if cfg.fx.is_some()
+        && !matches!(
+            cfg.out_format,
+            Format::Csv | Format::Ledger | Format::Beancount
+        )
+    {
+        return Err(BconvError::FxError(format!(
+            "курс записывается только в csv, ledger и beancount, а не в '{}'",
+            cfg.out_format
+        )));
+    }
 
-    let from = cfg.in_format;
-    let to = cfg.out_format;
+    let from = cfg.in_format.clone();
+    let to = cfg.out_format.clone();
 
-    println!();
-    match cfg.input_path {
-        Some(path) => println!("Читаю из '{}'", path.to_string_lossy()),
-        None => println!("Читаю из stdin"),
+    eprintln!();
+    match cfg.origin.inputs() {
+        _ if matches!(cfg.origin, Origin::Generate(_)) => eprintln!("Генерирую операции"),
+        [] => eprintln!("Читаю из stdin"),
+        [input] => eprintln!("Читаю из '{}'", input.path.to_string_lossy()),
+        inputs => {
+            eprintln!("Читаю из {} файлов:", inputs.len());
+            for input in inputs {
+                eprintln!("  '{}' ({})", input.path.to_string_lossy(), input.format);
+            }
+        }
     };
 
-    if from != to {
-        println!("Конвертирую из '{}' в '{}'", from, to);
+    if from != to && !matches!(cfg.origin, Origin::Generate(_)) {
+        eprintln!("Конвертирую из '{}' в '{}'", from, to);
     };
 
-    match cfg.output_path {
-        Some(path) => println!("Пишу в '{}'", path.to_string_lossy()),
-        None => {
-            println!("Пишу в output");
-            println!();
+    match (&cfg.split, &cfg.output_path) {
+        (Some((template, _)), _) => eprintln!("Пишу в файлы по шаблону '{template}'"),
+        (None, Some(path)) => eprintln!("Пишу в '{}'", path.to_string_lossy()),
+        (None, None) => {
+            eprintln!("Пишу в output");
+            eprintln!();
         }
     };
 
-    let converter = Converter { reader, writer };
-    converter.convert(from, to)?;
+    let converter = Converter::new(cfg);
+    let records = converter.process()?;
+    // Файл открывается только после обработки: при ошибке в ней прежний результат
+    // не обрезается. При разбиении файлы открываются, когда известны их пути.
+    match converter.split {
+        Some(_) => converter.write_split(&to, records)?,
+        None => {
+            let path = converter.output_path.as_deref();
+            let writer = get_writer(path, &to).map_err(BconvError::OutputError)?;
+            converter.write(writer, path, &to, records)?
+        }
+    }
 
     Ok(())
 }
 
-struct Converter<R: Read, W: Write> {
-    reader: R,
-    writer: W,
+/// Читает операции из источника, описанного в конфиге, без записи результата.
+pub fn read_records(cfg: Config) -> Result<CsvRecords, BconvError> {
+    let converter = Converter::new(cfg);
+    let records = converter.read_inputs()?;
+    let records = converter.filter(records)?;
+    let records = converter.convert_currency(records)?;
+    Ok(converter.categorize(records))
 }
 
-impl<R: Read, W: Write> Converter<R, W> {
-    pub fn convert(self, from: Format, to: Format) -> Result<(), BankError> {
-        match (from, to) {
-            (Format::Csv, Format::Json) => self.execute::<CsvRecords, JsonRecords>(),
-            (Format::Json, Format::Csv) => self.execute::<JsonRecords, CsvRecords>(),
-            (Format::Csv, Format::Csv) => self.execute::<CsvRecords, CsvRecords>(),
-            (Format::Json, Format::Json) => self.execute::<JsonRecords, JsonRecords>(),
+/// Читает операции каждого исходного файла отдельно, без объединения.
+pub fn read_each(cfg: Config) -> Result<Vec<CsvRecords>, BconvError> {
+    let converter = Converter::new(cfg);
+    converter
+        .origin
+        .inputs()
+        .iter()
+        .map(|input| converter.filter(converter.read_input(input)?))
+        .collect()
+}
+
+/// Конвертер читает записи любого формата в [CsvRecords] и уже из них
+/// пишет результат, поэтому каждому формату достаточно конвертации в `csv` и обратно.
+struct Converter {
+    origin: Origin,
+    in_format: Format,
+    merge_by_date: bool,
+    provenance: bool,
+    output_path: Option<PathBuf>,
+    split: Option<(Template, Option<usize>)>,
+    #[cfg(feature = "sqlite")]
+    sqlite_mode: SqliteMode,
+    #[cfg(feature = "sqlite")]
+    sqlite_where: Option<String>,
+    journal_options: JournalOptions,
+    table_options: TableOptions,
+    xml_options: XmlOptions,
+    layout: Option<FixedLayout>,
+    validate: bool,
+    filter: Option<Filter>,
+    fx: Option<FxOptions>,
+    categories: Option<Categories>,
+    sort: Option<Vec<SortKey>>,
+    columns: Columns,
+    dedupe: Option<(DedupeOptions, Keep)>,
+    party: Option<Party>,
+    signed: bool,
+    anonymizer: Option<Anonymizer>,
+    #[cfg(feature = "columnar")]
+    parquet_options: ParquetOptions,
+    #[cfg(feature = "columnar")]
+    arrow_options: ArrowOptions,
+}
+
+impl Converter {
+    fn new(cfg: Config) -> Self {
+        Self {
+            origin: cfg.origin,
+            in_format: cfg.in_format,
+            merge_by_date: cfg.merge_by_date,
+            provenance: cfg.provenance,
+            output_path: cfg.output_path,
+            split: cfg.split,
+            #[cfg(feature = "sqlite")]
+            sqlite_mode: cfg.sqlite_mode,
+            #[cfg(feature = "sqlite")]
+            sqlite_where: cfg.sqlite_where,
+            journal_options: cfg.journal_options,
+            table_options: cfg.table_options,
+            xml_options: cfg.xml_options,
+            layout: cfg.layout,
+            validate: cfg.validate,
+            filter: cfg.filter,
+            fx: cfg.fx,
+            categories: cfg.categories,
+            sort: cfg.sort,
+            columns: cfg.columns,
+            dedupe: cfg.dedupe,
+            party: cfg.party,
+            signed: cfg.signed,
+            anonymizer: cfg.anonymizer,
+            #[cfg(feature = "columnar")]
+            parquet_options: cfg.parquet_options,
+            #[cfg(feature = "columnar")]
+            arrow_options: cfg.arrow_options,
         }
     }
 
-    fn execute<T1, T2>(self) -> Result<(), BankError>
-    where
-        T1: Parse<T1> + Into<T2>,
-        for<'a> &'a T2: Print,
-    {
-        let records1 = banker::parse::<T1>(self.reader)?;
-        let records2: T2 = banker::convert_to(records1);
-        banker::print(self.writer, &records2)?;
+    /// Читает операции и готовит их к записи: фильтр, проверка, валюта, категории, дубликаты,
+    /// сортировка, знаки сумм, обезличивание.
+    fn process(&self) -> Result<CsvRecords, BconvError> {
+        let records = self.read_inputs()?;
+        let records = self.filter(records)?;
+
+        if self.validate {
+            let issues = banker::validate(records.list());
+            if !issues.is_empty() {
+                return Err(BconvError::ValidationError(issues));
+            }
+        }
+
+        let records = self.convert_currency(records)?;
+        let records = self.categorize(records);
+
+        let records = match &self.dedupe {
+            Some((options, keep)) => {
+                let count = records.list().len();
+                let records = dedupe::dedupe(records.into_parts(), options, *keep)
+                    .map_err(BconvError::DedupeError)?;
+                eprintln!("Удалено дубликатов: {}", count - records.len());
+                records.into()
+            }
+            None => records,
+        };
+
+        let records = match &self.sort {
+            Some(keys) => sort::sort(records.into_parts(), keys)
+                .map_err(BconvError::SortError)?
+                .into(),
+            None => records,
+        };
+
+        let records = match &self.party {
+            Some(party) if self.signed => party
+                .sign_amounts(records.into_parts())
+                .map_err(BconvError::DirectionError)?
+                .into(),
+            _ => records,
+        };
+
+        let records = match &self.anonymizer {
+            Some(anonymizer) => anonymizer
+                .apply(records.into_parts())
+                .map_err(BconvError::AnonymizeError)?
+                .into(),
+            None => records,
+        };
+
+        Ok(records)
+    }
+
+    /// Раскладывает операции по файлам из шаблона, недостающие каталоги создаются.
+    fn write_split(&self, to: &Format, records: CsvRecords) -> Result<(), BconvError> {
+        let Some((template, max_records)) = &self.split else {
+            unreachable!("разбиение проверяется перед записью")
+        };
+        let outputs = split::split(records.into_parts(), template, *max_records)
+            .map_err(BconvError::SplitError)?;
+
+        for output in &outputs {
+            if let Some(dir) = output.path.parent() {
+                fs::create_dir_all(dir).map_err(BconvError::OutputError)?;
+            }
+        }
+        for output in outputs {
+            let writer = get_writer(Some(&output.path), to).map_err(BconvError::OutputError)?;
+            eprintln!(
+                "  '{}': {}",
+                output.path.to_string_lossy(),
+                output.records.len()
+            );
+            self.write(writer, Some(&output.path), to, output.records.into())?;
+        }
 
         Ok(())
     }
-}
 
-fn get_reader(cfg: &Config) -> Result<Box<dyn Read>, io::Error> {
-    match &cfg.input_path {
-        Some(path) => {
-            let file = File::open(path)?;
-            Ok(Box::new(BufReader::new(file)))
+    fn filter(&self, records: CsvRecords) -> Result<CsvRecords, BconvError> {
+        match &self.filter {
+            Some(filter) => Ok(filter
+                .apply(records.into_parts())
+                .map_err(BconvError::FilterError)?
+                .into()),
+            None => Ok(records),
+        }
+    }
+
+    fn convert_currency(&self, records: CsvRecords) -> Result<CsvRecords, BconvError> {
+        match &self.fx {
+            Some(options) => Ok(fx::convert(
+                records.into_parts(),
+                &options.rates,
+                &options.from,
+                &options.to,
+                options.fallback_days,
+            )
+            .map_err(BconvError::FxError)?
+            .into()),
+            None => Ok(records),
+        }
+    }
+
+    /// Раскладывает операции по категориям и печатает операции без категории.
+    fn categorize(&self, records: CsvRecords) -> CsvRecords {
+        let Some(categories) = &self.categories else {
+            return records;
+        };
+        let records = categories.apply(records.into_parts());
+        let uncategorized = categorize::uncategorized(&records);
+        if !uncategorized.is_empty() {
+            eprintln!("Без категории: {}", uncategorized.len());
+            for index in uncategorized {
+                let record = &records[index];
+                eprintln!(
+                    "  {} {} {} -> {} {}",
+                    record.transaction,
+                    record.date,
+                    record.from_client,
+                    record.to_client,
+                    record.amount
+                );
+            }
+        }
+        records.into()
+    }
+
+    /// Читает операции из всех исходных файлов и объединяет их, без файлов читает stdin.
+    /// С настройками генератора операции не читаются, а генерируются.
+    fn read_inputs(&self) -> Result<CsvRecords, BconvError> {
+        let inputs = match &self.origin {
+            Origin::Generate(options) => {
+                return Ok(generate::generate(options)
+                    .map_err(BconvError::GenerateError)?
+                    .into());
+            }
+            Origin::Inputs(inputs) if inputs.is_empty() => {
+                return Ok(self.read(stdin().lock(), &self.in_format, None)?);
+            }
+            Origin::Inputs(inputs) => inputs,
+        };
+
+        let mut sources = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            let records = self.read_input(input)?.into_parts();
+            sources.push(match self.provenance {
+                true => merge::tag(records, &input.path.to_string_lossy()),
+                false => records,
+            });
+        }
+
+        Ok(merge::merge(sources, self.merge_by_date)
+            .map_err(BconvError::MergeError)?
+            .into())
+    }
+
+    /// Читает один исходный файл, при нескольких файлах ошибка указывает на файл.
+    fn read_input(&self, input: &Input) -> Result<CsvRecords, BconvError> {
+        self.read_file(input).map_err(|err| match err {
+            BconvError::AppError(err) if self.origin.inputs().len() > 1 => {
+                BconvError::SourceError(input.path.clone(), err)
+            }
+            err => err,
+        })
+    }
+
+    fn read_file(&self, input: &Input) -> Result<CsvRecords, BconvError> {
+        // Базу sqlite читает сама библиотека по пути.
+        #[cfg(feature = "sqlite")]
+        if input.format == Format::Sqlite {
+            return Ok(self.read(io::empty(), &input.format, Some(&input.path))?);
+        }
+
+        let file = File::open(&input.path).map_err(BconvError::InputError)?;
+        Ok(self.read(BufReader::new(file), &input.format, Some(&input.path))?)
+    }
+
+    fn read(
+        &self,
+        reader: impl Read,
+        from: &Format,
+        #[cfg_attr(not(feature = "sqlite"), allow(unused_variables))] path: Option<&Path>,
+    ) -> Result<CsvRecords, BankError> {
+        let records = match from {
+            Format::Csv => read::<CsvRecords>(reader)?,
+            Format::DebitCredit => read::<DebitCreditRecords>(reader)?,
+            Format::Json => read::<JsonRecords>(reader)?,
+            Format::Xml => XmlRecords::parse_with_options(reader, self.xml_options.clone())
+                .map_err(BankError::from)?
+                .into(),
+            Format::Yaml => read::<YamlRecords>(reader)?,
+            Format::Toml => read::<TomlRecords>(reader)?,
+            Format::Fixed => {
+                let layout = self.layout.clone().unwrap_or_default();
+                FixedRecords::parse_with_layout(reader, layout)
+                    .map_err(BankError::from)?
+                    .into()
+            }
+            Format::Ledger
+            | Format::Beancount
+            | Format::Table
+            | Format::Markdown
+            | Format::Html => {
+                unreachable!("читаемость формата проверяется в конфиге")
+            }
+            #[cfg(feature = "columnar")]
+            Format::Parquet => read::<ParquetRecords>(reader)?,
+            #[cfg(feature = "columnar")]
+            Format::Arrow => read::<ArrowRecords>(reader)?,
+            #[cfg(feature = "msgpack")]
+            Format::Msgpack => read::<MsgpackRecords>(reader)?,
+            #[cfg(feature = "cbor")]
+            Format::Cbor => read::<CborRecords>(reader)?,
+            #[cfg(feature = "sqlite")]
+            Format::Sqlite => {
+                let path = path.unwrap_or(Path::new(""));
+                SqliteRecords::load(path, self.sqlite_where.as_deref())
+                    .map_err(BankError::from)?
+                    .into()
+            }
+        };
+
+        Ok(records)
+    }
+
+    fn write(
+        &self,
+        writer: impl Write,
+        #[cfg_attr(not(feature = "sqlite"), allow(unused_variables))] path: Option<&Path>,
+        to: &Format,
+        records: CsvRecords,
+    ) -> Result<(), BankError> {
+        match to {
+            Format::Csv => banker::print(writer, &records.with_columns(self.columns.clone())),
+            Format::DebitCredit => {
+                let party = self.party.clone().unwrap_or_default();
+                let records = DebitCreditRecords::from(records).with_party(party);
+                banker::print(writer, &records)
+            }
+            Format::Json => write::<JsonRecords>(writer, records),
+            Format::Xml => {
+                let records = XmlRecords::from(records).with_options(self.xml_options.clone());
+                banker::print(writer, &records)
+            }
+            Format::Yaml => write::<YamlRecords>(writer, records),
+            Format::Toml => write::<TomlRecords>(writer, records),
+            Format::Fixed => {
+                let layout = self.layout.clone().unwrap_or_default();
+                let records = FixedRecords::from(records).with_layout(layout);
+                banker::print(writer, &records)
+            }
+            Format::Ledger => {
+                let records =
+                    LedgerRecords::from(records).with_options(self.journal_options.clone());
+                banker::print(writer, &records)
+            }
+            Format::Beancount => {
+                let records =
+                    BeancountRecords::from(records).with_options(self.journal_options.clone());
+                banker::print(writer, &records)
+            }
+            Format::Table => {
+                let records = TableRecords::from(records).with_options(self.table_options.clone());
+                banker::print(writer, &records)
+            }
+            Format::Markdown => write::<MarkdownRecords>(writer, records),
+            Format::Html => write::<HtmlRecords>(writer, records),
+            #[cfg(feature = "columnar")]
+            Format::Parquet => {
+                let records = ParquetRecords::from(records).with_options(self.parquet_options);
+                banker::print(writer, &records)
+            }
+            #[cfg(feature = "columnar")]
+            Format::Arrow => {
+                let records = ArrowRecords::from(records).with_options(self.arrow_options);
+                banker::print(writer, &records)
+            }
+            #[cfg(feature = "msgpack")]
+            Format::Msgpack => write::<MsgpackRecords>(writer, records),
+            #[cfg(feature = "cbor")]
+            Format::Cbor => write::<CborRecords>(writer, records),
+            #[cfg(feature = "sqlite")]
+            Format::Sqlite => {
+                let path = path.unwrap_or(Path::new(""));
+                let records = SqliteRecords::from(records).with_mode(self.sqlite_mode);
+                records.save(path).map_err(BankError::from)
+            }
         }
-        None => Ok(Box::new(stdin().lock())),
     }
 }
 
-fn get_writer(cfg: &Config) -> Result<Box<dyn Write>, io::Error> {
-    match &cfg.output_path {
+fn read<T>(reader: impl Read) -> Result<CsvRecords, BankError>
+where
+    T: Parse<T> + Into<CsvRecords>,
+{
+    let records = banker::parse::<T>(reader)?;
+    Ok(banker::convert_to(records))
+}
+
+fn write<T>(writer: impl Write, records: CsvRecords) -> Result<(), BankError>
+where
+    T: From<CsvRecords>,
+    for<'a> &'a T: Print,
+{
+    let records: T = banker::convert_to(records);
+    banker::print(writer, &records)
+}
+
+fn get_writer(
+    path: Option<&Path>,
+    #[cfg_attr(not(feature = "sqlite"), allow(unused_variables))] format: &Format,
+) -> Result<Box<dyn Write>, io::Error> {
+    // Файл базы sqlite нельзя обрезать, иначе пропадут операции для дописывания.
+    #[cfg(feature = "sqlite")]
+    if *format == Format::Sqlite {
+        return Ok(Box::new(io::sink()));
+    }
+
+    match path {
         Some(path) => {
-            let file = OpenOptions::new().create(true).write(true).open(path)?;
+            let file = OpenOptions::new()
+                .create(true)
+                .write(true)
+                .truncate(true)
+                .open(path)?;
             Ok(Box::new(BufWriter::new(file)))
         }
         None => Ok(Box::new(stdout().lock())),
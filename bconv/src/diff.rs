@@ -0,0 +1,30 @@
+use crate::cli::DiffFormat;
+use crate::config::Config;
+use crate::converter;
+use crate::error::BconvError;
+use banker::diff;
+use std::fs::File;
+use std::io::{BufWriter, Write, stdout};
+
+/// Сравнивает две выписки и печатает различия в stdout или в файл из `--output`.
+pub fn run(cfg: Config, key: &[String], format: DiffFormat) -> Result<(), BconvError> {
+    let output = cfg.output_path.clone();
+    let [old, new]: [_; 2] = converter::read_each(cfg)?
+        .try_into()
+        .map_err(|_| BconvError::DiffError("нужно ровно два файла".into()))?;
+    let diff =
+        diff::diff(old.into_parts(), new.into_parts(), key).map_err(BconvError::DiffError)?;
+
+    let mut writer: Box<dyn Write> = match output {
+        Some(path) => Box::new(BufWriter::new(
+            File::create(path).map_err(BconvError::OutputError)?,
+        )),
+        None => Box::new(stdout().lock()),
+    };
+    match format {
+        DiffFormat::Text => diff.print_text(&mut writer),
+        DiffFormat::Json => diff.print_json(&mut writer),
+    }
+    .and_then(|_| writer.flush())
+    .map_err(BconvError::OutputError)
+}
@@ -1,14 +1,35 @@
+use banker::direction::UnknownDirection;
 use banker::error::BankError;
+use banker::filter::FilterError;
+use banker::validation::Issue;
 
 use std::error::Error;
 use std::fmt::Display;
 use std::io;
+use std::path::PathBuf;
 
 #[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum BconvError {
     InputError(io::Error),
     OutputError(io::Error),
     AppError(BankError),
+    SourceError(PathBuf, BankError),
+    MergeError(String),
+    DirectionError(UnknownDirection),
+    ValidationError(Vec<Issue>),
+    RulesError(String),
+    DedupeError(String),
+    FilterError(FilterError),
+    SortError(String),
+    FxError(String),
+    AnonymizeError(String),
+    GenerateError(String),
+    SplitError(String),
+    StatsError(String),
+    DiffError(String),
+    ReconcileError(String),
+    RulesViolated(usize),
 }
 
 impl Error for BconvError {}
@@ -20,6 +41,30 @@ impl Display for BconvError {
             InputError(err) => write!(f, "ошибка с input: {err}"),
             OutputError(err) => write!(f, "ошибка с output: {err}"),
             AppError(err) => write!(f, "ошибка конвертации: {err}"),
+            SourceError(path, err) => {
+                write!(f, "ошибка конвертации '{}': {err}", path.to_string_lossy())
+            }
+            MergeError(err) => write!(f, "не получилось объединить файлы: {err}"),
+            DirectionError(err) => write!(f, "ошибка конвертации: {err}"),
+            RulesError(err) => write!(f, "некорректные правила: {err}"),
+            DedupeError(err) => write!(f, "не получилось удалить дубликаты: {err}"),
+            FilterError(err) => write!(f, "{err}"),
+            GenerateError(err) => write!(f, "не получилось сгенерировать операции: {err}"),
+            AnonymizeError(err) => write!(f, "не получилось обезличить операции: {err}"),
+            FxError(err) => write!(f, "не получилось пересчитать валюту: {err}"),
+            SortError(err) => write!(f, "не получилось отсортировать: {err}"),
+            SplitError(err) => write!(f, "не получилось разложить по файлам: {err}"),
+            StatsError(err) => write!(f, "не получилось посчитать итоги: {err}"),
+            DiffError(err) => write!(f, "не получилось сравнить выписки: {err}"),
+            ReconcileError(err) => write!(f, "не получилось сверить операции: {err}"),
+            RulesViolated(errors) => write!(f, "проверка не пройдена, ошибок: {errors}"),
+            ValidationError(issues) => {
+                write!(f, "ошибки в реквизитах банков: {}", issues.len())?;
+                for issue in issues {
+                    write!(f, "\n  {issue}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
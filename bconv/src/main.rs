@@ -1,19 +1,146 @@
 mod cli;
 mod config;
 mod converter;
+mod diff;
 mod error;
+mod reconcile;
+mod schema;
+mod stats;
+mod validate;
+
+use banker::dedupe::DedupeOptions;
+use banker::generate::GenerateOptions;
+use banker::reconcile::ReconcileOptions;
+use std::process;
 
 fn main() {
-    let cfg = match cli::parse().try_into() {
+    let mut cli = cli::parse();
+
+    if let Some(command) = cli.command.take() {
+        run_command(command, cli);
+        return;
+    }
+
+    let cfg = match cli.try_into() {
         Ok(cfg) => cfg,
         Err(err) => {
             handle_config_errors(err);
-            return;
+            process::exit(1);
         }
     };
 
     if let Err(err) = converter::convert(cfg) {
         eprintln!("{err}");
+        process::exit(1);
+    }
+}
+
+fn run_command(command: cli::Command, mut cli: cli::Cli) {
+    use cli::Command::*;
+
+    let result = match command {
+        Dedupe {
+            keys,
+            window_days,
+            keep,
+        } => match config::Config::try_from(cli) {
+            Ok(mut cfg) => {
+                let options = DedupeOptions { keys, window_days };
+                cfg.dedupe = Some((options, keep.into()));
+                converter::convert(cfg)
+            }
+            Err(err) => {
+                handle_config_errors(err);
+                process::exit(1);
+            }
+        },
+        Diff {
+            old,
+            new,
+            key,
+            report,
+        } => {
+            cli.input = vec![old, new];
+            match cli.try_into() {
+                Ok(cfg) => diff::run(cfg, &key, report),
+                Err(err) => {
+                    handle_config_errors(err);
+                    process::exit(1);
+                }
+            }
+        }
+        Reconcile {
+            statement,
+            ledger,
+            amount_epsilon,
+            date_days,
+            name_similarity,
+            report,
+        } => {
+            cli.input = vec![statement, ledger];
+            let options = ReconcileOptions {
+                amount_epsilon,
+                date_days,
+                name_similarity,
+            };
+            match cli.try_into() {
+                Ok(cfg) => reconcile::run(cfg, &options, report),
+                Err(err) => {
+                    handle_config_errors(err);
+                    process::exit(1);
+                }
+            }
+        }
+        Generate {
+            count,
+            seed,
+            clients,
+            amounts,
+            from,
+            to,
+        } => {
+            let options = GenerateOptions {
+                seed,
+                count,
+                clients,
+                amounts,
+                from,
+                to,
+            };
+            match config::Config::generate(cli, options) {
+                Ok(cfg) => converter::convert(cfg),
+                Err(err) => {
+                    handle_config_errors(err);
+                    process::exit(1);
+                }
+            }
+        }
+        Schema { format } => match config::xml_options(cli.xml) {
+            Ok(options) => schema::print(format, options),
+            Err(err) => {
+                handle_config_errors(err);
+                process::exit(1);
+            }
+        },
+        Stats { group_by, report } => match cli.try_into() {
+            Ok(cfg) => stats::run(cfg, &group_by, report),
+            Err(err) => {
+                handle_config_errors(err);
+                process::exit(1);
+            }
+        },
+        Validate { rules, report } => match cli.try_into() {
+            Ok(cfg) => validate::run(cfg, &rules, report),
+            Err(err) => {
+                handle_config_errors(err);
+                process::exit(1);
+            }
+        },
+    };
+
+    if let Err(err) = result {
+        eprintln!("{err}");
+        process::exit(1);
     }
 }
 
@@ -24,10 +151,90 @@ fn handle_config_errors(err: config::ConfigError) {
     eprintln!("Ошибка конфигурации: {err}");
 
     match err {
-        InFormatUndefined => {
+        InFormatUndefined | InputFormatUndefined(_) => {
             println!();
             println!("Подсказка: укажите формат явно через опцию --in-format.");
         }
+        InvalidInput(_) => {
+            println!();
+            println!(
+                "Подсказка: --input принимает файл, каталог или шаблон вроде 'statements/*.csv'."
+            );
+        }
+        InFormatNotReadable(_) => {
+            println!();
+            println!("Подсказка: журналы и отчёты доступны только в --out-format.");
+        }
+        InvalidXmlOptions(_) => {
+            println!();
+            println!(
+                "Подсказка: поля операции: {}.",
+                banker::records::FIELDS.join(", ")
+            );
+        }
+        LayoutRequired | InvalidLayout(_) => {
+            println!();
+            println!("Подсказка: передайте toml с колонками через опцию --layout.");
+        }
+        PartyRequired => {
+            println!();
+            println!("Подсказка: укажите нашего клиента через опцию --our-client.");
+        }
+        InvalidSort(_) | InvalidColumns(_) => {
+            println!();
+            println!(
+                "Подсказка: поля операции: {}.",
+                banker::records::FIELDS.join(", ")
+            );
+        }
+        ColumnsNotSupported(_) => {
+            println!();
+            println!("Подсказка: колонки можно выбрать для --out-format csv или table.");
+        }
+        InvalidSplit(_) => {
+            println!();
+            println!(
+                "Подсказка: поля операции в фигурных скобках: {}, у даты можно задать формат \
+                {{date:%Y-%m}}, {{part}} — номер части файла.",
+                banker::records::FIELDS.join(", ")
+            );
+        }
+        ProvenanceNotSupported(_) => {
+            println!();
+            println!("Подсказка: источник операций записывается только в --out-format csv.");
+        }
+        InvalidRates(_) => {
+            println!();
+            println!(
+                "Подсказка: курсы пишутся строками `2025-01-24,USD,RUB,101.25` под заголовком date,from,to,rate."
+            );
+        }
+        InvalidCategories(_) => {
+            println!();
+            println!(
+                "Подсказка: правило [[category]] задаёт name и условия counterparty, bank, \
+                min_amount, max_amount."
+            );
+        }
+        InvalidAnonymize(_) => {
+            println!();
+            println!(
+                "Подсказка: ключ можно создать командой `head -c 32 /dev/urandom | base64 > key`, \
+                --jitter-amount задаётся долей от 0 до 1, --jitter-days не больше 3650."
+            );
+        }
+        InvalidFilter(_) => {
+            println!();
+            println!(
+                "Подсказка: условия вида `поле оператор значение` объединяются через && и ||, \
+                строки пишутся в кавычках, даты как 2025-01-01."
+            );
+        }
+        #[cfg(feature = "sqlite")]
+        PathRequired(_) => {
+            println!();
+            println!("Подсказка: укажите путь к базе через опцию --input или --output.");
+        }
         InputRequired => {
             println!();
             println!("Подсказка: укажите путь к файлу через опцию --input.");
@@ -0,0 +1,35 @@
+use crate::cli::ReconcileFormat;
+use crate::config::Config;
+use crate::converter;
+use crate::error::BconvError;
+use banker::reconcile::{self, ReconcileOptions};
+use std::fs::File;
+use std::io::{BufWriter, Write, stdout};
+
+/// Сверяет выписку с учётом и печатает результат в stdout или в файл из `--output`.
+pub fn run(
+    cfg: Config,
+    options: &ReconcileOptions,
+    format: ReconcileFormat,
+) -> Result<(), BconvError> {
+    let output = cfg.output_path.clone();
+    let [statement, ledger]: [_; 2] = converter::read_each(cfg)?
+        .try_into()
+        .map_err(|_| BconvError::ReconcileError("нужно ровно два файла".into()))?;
+    let (statement, ledger) = (statement.list(), ledger.list());
+    let result =
+        reconcile::reconcile(statement, ledger, options).map_err(BconvError::ReconcileError)?;
+
+    let mut writer: Box<dyn Write> = match output {
+        Some(path) => Box::new(BufWriter::new(
+            File::create(path).map_err(BconvError::OutputError)?,
+        )),
+        None => Box::new(stdout().lock()),
+    };
+    match format {
+        ReconcileFormat::Text => result.print_text(&mut writer, statement, ledger),
+        ReconcileFormat::Json => result.print_json(&mut writer, statement, ledger),
+    }
+    .and_then(|_| writer.flush())
+    .map_err(BconvError::OutputError)
+}
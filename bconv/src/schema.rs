@@ -0,0 +1,14 @@
+use crate::cli::SchemaFormat;
+use crate::error::BconvError;
+use banker::records::XmlOptions;
+use std::io::stdout;
+
+pub fn print(format: SchemaFormat, xml_options: XmlOptions) -> Result<(), BconvError> {
+    let writer = stdout().lock();
+
+    match format {
+        SchemaFormat::Xml => xml_options
+            .print_schema(writer)
+            .map_err(BconvError::OutputError),
+    }
+}
@@ -0,0 +1,28 @@
+use crate::cli::StatsFormat;
+use crate::config::Config;
+use crate::converter;
+use crate::error::BconvError;
+use banker::stats;
+use std::fs::File;
+use std::io::{BufWriter, Write, stdout};
+
+/// Считает итоги по операциям из источника и печатает их в stdout или в файл из `--output`.
+pub fn run(cfg: Config, group_by: &[String], format: StatsFormat) -> Result<(), BconvError> {
+    let output = cfg.output_path.clone();
+    let records = converter::read_records(cfg)?;
+    let summary = stats::aggregate(records.list(), group_by).map_err(BconvError::StatsError)?;
+
+    let mut writer: Box<dyn Write> = match output {
+        Some(path) => Box::new(BufWriter::new(
+            File::create(path).map_err(BconvError::OutputError)?,
+        )),
+        None => Box::new(stdout().lock()),
+    };
+    match format {
+        StatsFormat::Table => summary.print_table(&mut writer),
+        StatsFormat::Csv => summary.print_csv(&mut writer),
+        StatsFormat::Json => summary.print_json(&mut writer),
+    }
+    .and_then(|_| writer.flush())
+    .map_err(BconvError::OutputError)
+}
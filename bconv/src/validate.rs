@@ -0,0 +1,33 @@
+use crate::cli::ReportFormat;
+use crate::config::Config;
+use crate::converter;
+use crate::error::BconvError;
+use banker::rules::Rules;
+use std::fs;
+use std::io::{Write, stdout};
+use std::path::Path;
+
+/// Проверяет операции из источника по правилам и печатает отчёт о нарушениях.
+/// Ошибка, если хотя бы одно правило с важностью `error` нарушено.
+pub fn run(cfg: Config, rules: &Path, format: ReportFormat) -> Result<(), BconvError> {
+    let rules = fs::read_to_string(rules)
+        .map_err(|err| BconvError::RulesError(format!("'{}': {err}", rules.to_string_lossy())))?;
+    let rules = Rules::from_toml(&rules).map_err(|err| BconvError::RulesError(err.to_string()))?;
+
+    let records = converter::read_records(cfg)?;
+    let report = rules.check(records.list());
+
+    let mut writer = stdout().lock();
+    match format {
+        ReportFormat::Text => writeln!(writer, "{report}"),
+        ReportFormat::Json => serde_json::to_writer_pretty(&mut writer, &report)
+            .map_err(Into::into)
+            .and_then(|_| writeln!(writer)),
+    }
+    .map_err(BconvError::OutputError)?;
+
+    match report.errors().count() {
+        0 => Ok(()),
+        errors => Err(BconvError::RulesViolated(errors)),
+    }
+}